@@ -182,6 +182,21 @@ pub fn is_common_password<S: AsRef<str>>(password: S) -> bool {
     COMMON_PASSWORDS.binary_search(&password.as_ref()).is_ok()
 }
 
+#[cfg(debug_assertions)]
+#[cfg(feature = "common-password")]
+/// The common passwords, in the order of the table.
+pub(crate) fn common_passwords() -> impl Iterator<Item = &'static str> {
+    // the table has no escaped quotes, so every other piece between quotes is a password
+    COMMON_PASSWORDS.split('"').skip(1).step_by(2)
+}
+
+#[cfg(not(debug_assertions))]
+#[cfg(feature = "common-password")]
+/// The common passwords, in the order of the table.
+pub(crate) fn common_passwords() -> impl Iterator<Item = &'static str> {
+    COMMON_PASSWORDS.iter().copied()
+}
+
 /// Analyze a password.
 pub fn analyze<S: AsRef<str>>(password: S) -> AnalyzedPassword {
     let password = password.as_ref();
@@ -0,0 +1,142 @@
+use rand::seq::SliceRandom;
+
+use crate::{PasswordGenerator, PasswordGeneratorIter};
+
+/// This struct can help you generate passwords composed of ordered segments, like "4 uppercase letters + 2 digits + 1 symbol". Each segment is generated by its own `PasswordGenerator` settings, and the characters of all segments can be shuffled at the end.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompositePasswordGenerator {
+    /// The segments, in order.
+    ///
+    /// Default: `[]`
+    pub segments: Vec<PasswordGenerator>,
+    /// Whether to shuffle the characters of all segments uniformly at the end.
+    ///
+    /// Default: `false`
+    pub shuffle:  bool,
+}
+
+impl CompositePasswordGenerator {
+    /// Create a `CompositePasswordGenerator` instance without segments.
+    #[inline]
+    pub const fn new() -> CompositePasswordGenerator {
+        CompositePasswordGenerator {
+            segments: Vec::new(), shuffle: false
+        }
+    }
+
+    /// Append a segment.
+    #[inline]
+    pub fn segment(mut self, segment: PasswordGenerator) -> CompositePasswordGenerator {
+        self.segments.push(segment);
+
+        self
+    }
+
+    /// Whether to shuffle the characters of all segments uniformly at the end.
+    #[inline]
+    pub const fn shuffle(mut self, shuffle: bool) -> CompositePasswordGenerator {
+        self.shuffle = shuffle;
+
+        self
+    }
+}
+
+impl CompositePasswordGenerator {
+    fn try_iters(&self) -> Result<Vec<PasswordGeneratorIter>, &'static str> {
+        if self.segments.is_empty() {
+            return Err("You need to add at least one segment.");
+        }
+
+        let iters: Vec<PasswordGeneratorIter> =
+            self.segments.iter().map(PasswordGenerator::try_iter).collect::<Result<_, _>>()?;
+
+        // the arrangements of shuffled segments and the entropy are counted with fixed lengths
+        if iters.iter().any(|iter| iter.length != iter.max_length) {
+            return Err("The segments cannot have length ranges.");
+        }
+
+        // offensive words and confusable pairs can be formed across the boundaries of segments or by shuffling
+        if self.segments.iter().any(|segment| segment.exclude_offensive_words) {
+            return Err("The segments cannot exclude offensive words.");
+        }
+
+        if self.segments.iter().any(|segment| segment.transcription_safe) {
+            return Err("The segments cannot be transcription-safe.");
+        }
+
+        Ok(iters)
+    }
+
+    /// The entropy of the generated passwords, in bits.
+    ///
+    /// Without shuffling, it is the sum of the entropy of the segments. With shuffling, if the characters of the segments do not overlap, the arrangement of the segments can be told from a password, so the base-2 logarithm of the count of arrangements is added exactly. Otherwise, different arrangements may produce the same password, so the passwords are not equally likely and an error is returned. Use `entropy_lower_bound` in that case.
+    pub fn entropy(&self) -> Result<f64, &'static str> {
+        let iters = self.try_iters()?;
+
+        let mut entropy: f64 = iters.iter().map(PasswordGeneratorIter::entropy).sum();
+
+        if self.shuffle {
+            let sets: Vec<Vec<char>> = iters.iter().map(|iter| iter.pool.concat()).collect();
+
+            let disjoint = sets
+                .iter()
+                .enumerate()
+                .all(|(i, a)| sets.iter().skip(i + 1).all(|b| a.iter().all(|c| !b.contains(c))));
+
+            if !disjoint {
+                return Err("The entropy of shuffled segments whose characters overlap cannot be \
+                            computed exactly.");
+            }
+
+            let log2_factorial = |n: usize| (2..=n).map(|k| (k as f64).log2()).sum::<f64>();
+
+            let total_length = iters.iter().map(|iter| iter.length).sum();
+
+            entropy += log2_factorial(total_length)
+                - iters.iter().map(|iter| log2_factorial(iter.length)).sum::<f64>();
+        }
+
+        Ok(entropy)
+    }
+
+    /// A lower bound of the entropy of the generated passwords, in bits, which is the sum of the entropy of the segments. Shuffling never decreases it, so it is also valid for shuffled segments whose characters overlap.
+    pub fn entropy_lower_bound(&self) -> Result<f64, &'static str> {
+        Ok(self.try_iters()?.iter().map(PasswordGeneratorIter::entropy).sum())
+    }
+
+    /// Generate random passwords.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        let iters = self.try_iters()?;
+
+        let total_length = iters.iter().map(|iter| iter.length).sum();
+
+        let mut rng = rand::rng();
+        let mut segment = String::new();
+        let mut chars = Vec::with_capacity(total_length);
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            chars.clear();
+
+            for iter in iters.iter() {
+                iter.fill_valid_password(&mut rng, &mut segment);
+
+                chars.extend(segment.chars());
+            }
+
+            if self.shuffle {
+                chars.shuffle(&mut rng);
+            }
+
+            result.push(chars.iter().collect());
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random password.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// An error which can be returned when generating from an `ExternalEntropy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalEntropyError {
+    /// The settings of the generator are invalid.
+    InvalidSettings(&'static str),
+    /// The input ran out. `needed` is the count of additional rolls or bytes with which the generation finishes if none of them is rejected, which is also the least count which may finish it.
+    Exhausted { needed: usize },
+}
+
+impl Display for ExternalEntropyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExternalEntropyError::InvalidSettings(message) => f.write_str(message),
+            ExternalEntropyError::Exhausted {
+                needed,
+            } => {
+                write!(f, "the input of entropy ran out, {needed} more rolls or bytes are needed")
+            },
+        }
+    }
+}
+
+impl Error for ExternalEntropyError {}
+
+impl From<&'static str> for ExternalEntropyError {
+    #[inline]
+    fn from(message: &'static str) -> Self {
+        ExternalEntropyError::InvalidSettings(message)
+    }
+}
+
+/// A user-supplied source of entropy, like physical dice rolls or bytes from a hardware random number generator, for generating passwords on air-gapped machines.
+///
+/// The input is read as digits of a base, 6 for dice and 256 for bytes. To pick one of `n` items, the fewest digits whose count of combinations `m` is at least `n` are read as a number, which is rejected and read again if it is not smaller than `m - m % n`, so every item is picked without bias. The generators read the input deterministically and consume nothing if it runs out, so more rolls or bytes can be appended to the same input and the generation can be repeated with the same result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalEntropy {
+    base:     u32,
+    digits:   Vec<u8>,
+    position: usize,
+}
+
+impl ExternalEntropy {
+    /// Create an `ExternalEntropy` instance from the rolls of six-sided dice, like `"3 6 1 5 2 4"`. Each roll is a digit from `1` to `6`, and whitespace is ignored.
+    #[inline]
+    pub fn from_dice_rolls<S: AsRef<str>>(rolls: S) -> Result<ExternalEntropy, &'static str> {
+        let mut entropy = ExternalEntropy {
+            base: 6, digits: Vec::new(), position: 0
+        };
+
+        entropy.extend_dice_rolls(rolls)?;
+
+        Ok(entropy)
+    }
+
+    /// Create an `ExternalEntropy` instance from random bytes.
+    #[inline]
+    pub fn from_bytes<B: AsRef<[u8]>>(bytes: B) -> ExternalEntropy {
+        ExternalEntropy {
+            base: 256, digits: bytes.as_ref().to_vec(), position: 0
+        }
+    }
+
+    /// Append more dice rolls to an instance created from dice rolls.
+    pub fn extend_dice_rolls<S: AsRef<str>>(&mut self, rolls: S) -> Result<(), &'static str> {
+        if self.base != 6 {
+            return Err("Dice rolls cannot be appended to bytes.");
+        }
+
+        let rolls = rolls.as_ref();
+
+        if rolls.chars().any(|c| !matches!(c, '1'..='6') && !c.is_whitespace()) {
+            return Err("Dice rolls can only be digits from 1 to 6.");
+        }
+
+        self.digits.extend(rolls.bytes().filter(u8::is_ascii_digit).map(|b| b - b'1'));
+
+        Ok(())
+    }
+
+    /// Append more bytes to an instance created from bytes.
+    pub fn extend_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), &'static str> {
+        if self.base != 256 {
+            return Err("Bytes cannot be appended to dice rolls.");
+        }
+
+        self.digits.extend_from_slice(bytes.as_ref());
+
+        Ok(())
+    }
+
+    /// The count of rolls or bytes which have been read.
+    #[inline]
+    pub const fn consumed(&self) -> usize {
+        self.position
+    }
+
+    /// The count of rolls or bytes which have not been read.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.digits.len() - self.position
+    }
+
+    /// The count of digits read at a time to pick one of `n` items.
+    pub(crate) fn digits_per_pick(&self, n: usize) -> usize {
+        let mut combinations = 1u128;
+        let mut count = 0;
+
+        while combinations < n as u128 {
+            combinations *= self.base as u128;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Pick one of `n` items without bias. If the input runs out, the count of digits missing for this pick is returned as the error.
+    pub(crate) fn pick(&mut self, n: usize) -> Result<usize, usize> {
+        debug_assert!(n > 0);
+
+        let count = self.digits_per_pick(n);
+        let combinations = (self.base as u128).pow(count as u32);
+        let zone = combinations - combinations % n as u128;
+
+        loop {
+            if self.remaining() < count {
+                return Err(count - self.remaining());
+            }
+
+            let value = self.digits[self.position..self.position + count]
+                .iter()
+                .fold(0u128, |value, &digit| value * self.base as u128 + digit as u128);
+
+            self.position += count;
+
+            if value < zone {
+                return Ok((value % n as u128) as usize);
+            }
+        }
+    }
+
+    /// Run a generation. If it fails, nothing is consumed, so that it can be repeated after more input is appended.
+    pub(crate) fn generate<T, F: FnOnce(&mut Self) -> Result<T, ExternalEntropyError>>(
+        &mut self,
+        f: F,
+    ) -> Result<T, ExternalEntropyError> {
+        let position = self.position;
+
+        let result = f(self);
+
+        if result.is_err() {
+            self.position = position;
+        }
+
+        result
+    }
+
+    /// Pick one of `n` items for the item at `index` of `total` items picked the same way. If the input runs out, the count of digits missing for the remaining items is returned as the error.
+    #[inline]
+    pub(crate) fn pick_nth(
+        &mut self,
+        n: usize,
+        index: usize,
+        total: usize,
+    ) -> Result<usize, ExternalEntropyError> {
+        self.pick(n).map_err(|missing| ExternalEntropyError::Exhausted {
+            needed: missing + (total - index - 1) * self.digits_per_pick(n),
+        })
+    }
+}
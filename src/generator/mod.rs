@@ -1,5 +1,26 @@
+#[cfg(feature = "rayon")]
+use std::collections::HashSet;
+use std::{
+    borrow::Cow,
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+    io::{self, Write},
+    ops::{Deref, RangeInclusive},
+    str::FromStr,
+};
+
 use rand::{Rng, RngExt};
 
+use crate::{
+    ExternalEntropy, ExternalEntropyError,
+    offensive::{CleanSampler, log2_count_clean},
+};
+
+mod self_test;
+
+pub use self_test::{ChiSquareTest, CutoffTest, SelfTestReport};
+
 static NUMBERS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
 static NUMBERS_EXCLUDE_SIMILAR: [char; 8] = ['2', '3', '4', '5', '6', '7', '8', '9'];
 
@@ -32,21 +53,108 @@ static SYMBOLS_EXCLUDE_SIMILAR: [char; 28] = [
 
 static SPACE: [char; 1] = [' '];
 
+#[inline]
+fn char_mask(c: char) -> u8 {
+    match c {
+        '0'..='9' => 0b0000_0001,
+        'a'..='z' => 0b0000_0010,
+        'A'..='Z' => 0b0000_0100,
+        '!'..='/' | ':'..='@' | '['..='`' | '{'..='~' => 0b0000_1000,
+        ' ' => 0b0001_0000,
+        _ => 0,
+    }
+}
+
+/// Compute the base-2 logarithm of the count of strings of `length` characters by dynamic programming over an extra state, like `offensive::log2_count_clean` without the automaton.
+fn log2_count<C: FnMut(usize, usize) -> Vec<(char, usize)>, A: Fn(usize) -> bool>(
+    length: usize,
+    extras: usize,
+    initial_extra: usize,
+    mut choices: C,
+    accept: A,
+) -> f64 {
+    let mut counts = vec![0f64; extras];
+    let mut log2_scale = 0f64;
+
+    counts[initial_extra] = 1.0;
+
+    for position in 0..length {
+        let mut next_counts = vec![0f64; extras];
+
+        for (extra, &count) in counts.iter().enumerate() {
+            if count == 0.0 {
+                continue;
+            }
+
+            for (_, next_extra) in choices(position, extra) {
+                next_counts[next_extra] += count;
+            }
+        }
+
+        // rescale to keep the counts in the range of `f64`
+        let sum: f64 = next_counts.iter().sum();
+
+        if sum == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+
+        for count in next_counts.iter_mut() {
+            *count /= sum;
+        }
+
+        log2_scale += sum.log2();
+        counts = next_counts;
+    }
+
+    let accepted: f64 =
+        counts.iter().enumerate().filter(|&(extra, _)| accept(extra)).map(|(_, count)| count).sum();
+
+    log2_scale + accepted.log2()
+}
+
+/// The base-2 logarithm of the sum of `2^x` for each `x`, without overflowing.
+fn log2_sum_exp2<I: Iterator<Item = f64> + Clone>(xs: I) -> f64 {
+    let max = xs.clone().fold(f64::NEG_INFINITY, f64::max);
+
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+
+    max + xs.map(|x| (x - max).exp2()).sum::<f64>().log2()
+}
+
+/// How the length of each password is picked from a length range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LengthDistribution {
+    /// Every length in the range is equally likely.
+    #[default]
+    Uniform,
+    /// Every length is picked in proportion to its count of passwords, so every password in the range is equally likely and longer lengths are favored.
+    Proportional,
+}
+
 /// This struct can help you continually generate passwords.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PasswordGeneratorIter {
-    pool:        Vec<&'static [char]>,
-    total_len:   usize,
-    length:      usize,
-    target_mask: u8,
-    strict:      bool,
+    pub(crate) pool:                    Vec<&'static [char]>,
+    pub(crate) total_len:               usize,
+    pub(crate) length:                  usize,
+    pub(crate) max_length:              usize,
+    pub(crate) length_distribution:     LengthDistribution,
+    /// The cumulative probabilities of the lengths, only for `LengthDistribution::Proportional`.
+    pub(crate) length_weights:          Vec<f64>,
+    pub(crate) target_mask:             u8,
+    pub(crate) strict:                  bool,
+    pub(crate) exclude_offensive_words: bool,
+    /// The forbidden adjacent pairs of characters in the pool, empty if the transcription-safe mode is disabled.
+    pub(crate) confusable_pairs:        Vec<[char; 2]>,
+    /// The table to pick characters without offensive words and confusable pairs, `None` if neither is excluded.
+    pub(crate) sampler:                 Option<CleanSampler>,
 }
 
 impl PasswordGeneratorIter {
     #[inline]
-    fn pick_char<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        let mut index = rng.random_range(..self.total_len);
-
+    fn char_at(&self, mut index: usize) -> char {
         for slice in &self.pool {
             if index < slice.len() {
                 return slice[index];
@@ -58,33 +166,130 @@ impl PasswordGeneratorIter {
         unreachable!()
     }
 
-    fn fill_password<R: Rng + ?Sized>(&self, rng: &mut R, password: &mut String) -> u8 {
-        #[inline]
-        fn char_mask(c: char) -> u8 {
-            match c {
-                '0'..='9' => 0b0000_0001,
-                'a'..='z' => 0b0000_0010,
-                'A'..='Z' => 0b0000_0100,
-                '!'..='/' | ':'..='@' | '['..='`' | '{'..='~' => 0b0000_1000,
-                ' ' => 0b0001_0000,
-                _ => 0,
-            }
+    #[inline]
+    fn pick_char<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        self.char_at(rng.random_range(..self.total_len))
+    }
+
+    /// The length whose cumulative probability range contains `r`, which is in `[0, 1)`.
+    #[inline]
+    fn length_at(&self, r: f64) -> usize {
+        self.length
+            + self.length_weights.partition_point(|&w| w <= r).min(self.max_length - self.length)
+    }
+
+    #[inline]
+    fn pick_length<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        if self.length == self.max_length {
+            return self.length;
         }
 
+        match self.length_distribution {
+            LengthDistribution::Uniform => rng.random_range(self.length..=self.max_length),
+            LengthDistribution::Proportional => self.length_at(rng.random()),
+        }
+    }
+
+    /// Pick the characters of a password independently and uniformly from the pool, ignoring the offensive word filter and the confusable pairs.
+    #[inline]
+    pub(crate) fn fill_unfiltered_password<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        length: usize,
+        password: &mut String,
+    ) {
         password.clear();
 
-        let mut mask = 0;
+        for _ in 0..length {
+            password.push(self.pick_char(rng));
+        }
+    }
 
-        for _ in 0..self.length {
-            let c = self.pick_char(rng);
+    /// Pick the characters of a password and return the mask of their kinds. `pick(position, n)` returns a uniformly random index in `[0, n)` for the character at a position.
+    ///
+    /// If offensive words or confusable pairs are excluded, each character is picked in proportion to the count of valid passwords starting with the picked characters, so every valid password is equally likely and nothing is rejected.
+    fn fill_password_with<E, F: FnMut(usize, usize) -> Result<usize, E>, P: FnMut(char)>(
+        &self,
+        length: usize,
+        mut pick: F,
+        mut push: P,
+    ) -> Result<u8, E> {
+        let mut mask = 0;
 
-            password.push(c);
-            mask |= char_mask(c);
+        match &self.sampler {
+            Some(sampler) => {
+                sampler.sample(
+                    length,
+                    |position| Ok(pick(position, 1 << 53)? as f64 / (1u64 << 53) as f64),
+                    |_, c| {
+                        push(c);
+                        mask |= char_mask(c);
+                    },
+                )?;
+            },
+            None => {
+                for position in 0..length {
+                    let c = self.char_at(pick(position, self.total_len)?);
+
+                    push(c);
+                    mask |= char_mask(c);
+                }
+            },
         }
 
+        Ok(mask)
+    }
+
+    #[inline]
+    fn fill_password<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        length: usize,
+        password: &mut String,
+    ) -> u8 {
+        password.clear();
+
+        let Ok(mask) = self.fill_password_with::<Infallible, _, _>(
+            length,
+            |_, n| Ok(rng.random_range(..n)),
+            |c| password.push(c),
+        );
+
+        mask
+    }
+
+    /// Whether the kinds of characters satisfy the strict rules. The other rules are satisfied while picking the characters.
+    #[inline]
+    fn is_valid(&self, mask: u8) -> bool {
+        !self.strict || mask == self.target_mask
+    }
+
+    /// The length is picked before the rejection, so that the rejection does not change the distribution of lengths. Only the strict rules reject passwords, and most passwords satisfy them unless the length is close to the count of kinds of characters.
+    #[inline]
+    pub(crate) fn fill_valid_password<R: Rng + ?Sized>(&self, rng: &mut R, password: &mut String) {
+        let length = self.pick_length(rng);
+
+        while !self.is_valid(self.fill_password(rng, length, password)) {}
+    }
+
+    fn fill_password_bytes<R: Rng + ?Sized>(&self, rng: &mut R, buffer: &mut [u8]) -> u8 {
+        let mut bytes = buffer.iter_mut();
+
+        // all characters in the pool are ASCII
+        let Ok(mask) = self.fill_password_with::<Infallible, _, _>(
+            bytes.len(),
+            |_, n| Ok(rng.random_range(..n)),
+            |c| *bytes.next().unwrap() = c as u8,
+        );
+
         mask
     }
 
+    #[inline]
+    fn fill_valid_password_bytes<R: Rng + ?Sized>(&self, rng: &mut R, buffer: &mut [u8]) {
+        while !self.is_valid(self.fill_password_bytes(rng, buffer)) {}
+    }
+
     /// Generate random passwords.
     pub fn generate(&self, count: usize) -> Vec<String> {
         debug_assert_ne!(0, self.target_mask);
@@ -93,14 +298,9 @@ impl PasswordGeneratorIter {
         let mut result = Vec::with_capacity(count);
 
         for _ in 0..count {
-            let mut password = String::with_capacity(self.length);
-            let mut mask = self.fill_password(&mut rng, &mut password);
+            let mut password = String::with_capacity(self.max_length);
 
-            if self.strict {
-                while mask != self.target_mask {
-                    mask = self.fill_password(&mut rng, &mut password);
-                }
-            }
+            self.fill_valid_password(&mut rng, &mut password);
 
             result.push(password);
         }
@@ -108,11 +308,324 @@ impl PasswordGeneratorIter {
         result
     }
 
+    /// Generate random passwords and write them into a writer one by one, separated by the separator. Only one buffer is used, so the memory usage does not grow with the count.
+    pub fn generate_into<W: Write>(
+        &self,
+        count: usize,
+        mut writer: W,
+        separator: &str,
+    ) -> io::Result<()> {
+        debug_assert_ne!(0, self.target_mask);
+
+        let mut rng = rand::rng();
+        let mut password = String::with_capacity(self.max_length);
+
+        for i in 0..count {
+            if i > 0 {
+                writer.write_all(separator.as_bytes())?;
+            }
+
+            self.fill_valid_password(&mut rng, &mut password);
+
+            writer.write_all(password.as_bytes())?;
+        }
+
+        writer.flush()
+    }
+
     /// Generate a random password.
     #[inline]
     pub fn generate_one(&self) -> String {
         self.generate(1).remove(0)
     }
+
+    /// The sorted first characters of the confusable pairs. The class of the last character is `i + 1` if it is the `i`-th of them, or `0` if it starts no pair.
+    fn confusable_firsts(&self) -> Vec<char> {
+        let mut firsts: Vec<char> = self.confusable_pairs.iter().map(|pair| pair[0]).collect();
+
+        firsts.sort_unstable();
+        firsts.dedup();
+
+        firsts
+    }
+
+    /// The characters which can follow a character of a class, with their classes.
+    fn class_choices(&self, pool: &[char], firsts: &[char], class: usize) -> Vec<(char, usize)> {
+        pool.iter()
+            .filter(|&&c| class == 0 || !self.confusable_pairs.contains(&[firsts[class - 1], c]))
+            .map(|&c| (c, firsts.binary_search(&c).map_or(0, |i| i + 1)))
+            .collect()
+    }
+
+    /// The count of the values picked for each character, which is the size of the pool, or `2^53` if the characters are picked in proportion to the counts of valid passwords.
+    #[inline]
+    fn pick_range(&self) -> usize {
+        if self.sampler.is_some() { 1 << 53 } else { self.total_len }
+    }
+
+    /// The base-2 logarithm of the count of passwords of a length which satisfy the strict rules if enabled, do not contain offensive words if excluded, and do not contain confusable pairs.
+    fn log2_count_valid(&self, length: usize) -> f64 {
+        let pool = self.pool.concat();
+        let firsts = self.confusable_firsts();
+
+        let classes = firsts.len() + 1;
+        let masks = if self.strict { usize::from(self.target_mask) + 1 } else { 1 };
+
+        let choices = |_, extra: usize| {
+            let (mask, class) = (extra / classes, extra % classes);
+
+            self.class_choices(&pool, &firsts, class)
+                .into_iter()
+                .map(|(c, next_class)| {
+                    let mask = if self.strict { mask | usize::from(char_mask(c)) } else { 0 };
+
+                    (c, mask * classes + next_class)
+                })
+                .collect()
+        };
+
+        let accept =
+            |extra: usize| !self.strict || extra / classes == usize::from(self.target_mask);
+
+        if self.exclude_offensive_words {
+            log2_count_clean(length, masks * classes, 0, choices, accept)
+        } else {
+            log2_count(length, masks * classes, 0, choices, accept)
+        }
+    }
+
+    /// The base-2 logarithm of the count of possible passwords of a length.
+    fn entropy_of_length(&self, length: usize) -> f64 {
+        if self.exclude_offensive_words || !self.confusable_pairs.is_empty() {
+            return self.log2_count_valid(length);
+        }
+
+        let length = length as f64;
+        let total_len = self.total_len as f64;
+
+        if !self.strict {
+            return length * total_len.log2();
+        }
+
+        // inclusion-exclusion over the kinds of characters which are missing, divided by `total_len ^ length`
+        let mut ratio = 0f64;
+
+        for missing in 0..(1usize << self.pool.len()) {
+            let missing_len: usize = self
+                .pool
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| missing & (1 << i) != 0)
+                .map(|(_, slice)| slice.len())
+                .sum();
+
+            let term = ((total_len - missing_len as f64) / total_len).powf(length);
+
+            if missing.count_ones() % 2 == 0 {
+                ratio += term;
+            } else {
+                ratio -= term;
+            }
+        }
+
+        length * total_len.log2() + ratio.log2()
+    }
+
+    /// The entropy of the generated passwords, in bits, which is the base-2 logarithm of the count of possible passwords. If offensive words or confusable pairs are excluded, the excluded passwords are not counted, so the entropy loss is taken into account exactly.
+    ///
+    /// With a length range, the choice of the length is included. Passwords of different lengths are not equally likely under `LengthDistribution::Uniform`, so the min-entropy, which is the negative base-2 logarithm of the probability of the most likely password, is reported. It is the base-2 logarithm of the count of lengths plus the entropy of the length with the fewest passwords, usually the minimum length. Under `LengthDistribution::Proportional`, every password is equally likely, so it is the base-2 logarithm of the count of all possible passwords.
+    pub fn entropy(&self) -> f64 {
+        if self.length == self.max_length {
+            return self.entropy_of_length(self.length);
+        }
+
+        let entropies =
+            (self.length..=self.max_length).map(|length| self.entropy_of_length(length));
+
+        match self.length_distribution {
+            LengthDistribution::Uniform => {
+                ((self.max_length - self.length + 1) as f64).log2()
+                    + entropies.fold(f64::INFINITY, f64::min)
+            },
+            LengthDistribution::Proportional => log2_sum_exp2(entropies),
+        }
+    }
+
+    /// Generate a random password into a `String`, replacing its content. The allocated capacity of the `String` is reused.
+    #[inline]
+    pub fn fill_string(&self, password: &mut String) {
+        debug_assert_ne!(0, self.target_mask);
+
+        self.fill_valid_password(&mut rand::rng(), password);
+    }
+
+    /// Generate a random password into the beginning of a byte buffer, without allocating. The buffer needs to be at least as long as the maximum length of passwords. The password is returned as a `&str` borrowed from the buffer.
+    pub fn fill_bytes<'a>(&self, buffer: &'a mut [u8]) -> Result<&'a str, &'static str> {
+        debug_assert_ne!(0, self.target_mask);
+
+        if buffer.len() < self.max_length {
+            return Err("The buffer is too short.");
+        }
+
+        let mut rng = rand::rng();
+
+        let buffer = &mut buffer[..self.pick_length(&mut rng)];
+
+        self.fill_valid_password_bytes(&mut rng, buffer);
+
+        Ok(std::str::from_utf8(buffer).unwrap())
+    }
+
+    /// Generate a random password into a fixed-capacity array on the stack, without allocating. `N` needs to be at least the maximum length of passwords.
+    #[inline]
+    pub fn generate_array<const N: usize>(&self) -> Result<PasswordArray<N>, &'static str> {
+        let mut array = PasswordArray {
+            buffer: [0; N], length: 0
+        };
+
+        array.length = self.fill_bytes(&mut array.buffer)?.len();
+
+        Ok(array)
+    }
+}
+
+/// A password stored in a fixed-capacity array, which can be created by the `generate_array` method of `PasswordGeneratorIter`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PasswordArray<const N: usize> {
+    buffer: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> PasswordArray<N> {
+    /// Get the password as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buffer[..self.length]).unwrap()
+    }
+
+    /// Get the password as bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.length]
+    }
+}
+
+impl<const N: usize> Deref for PasswordArray<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for PasswordArray<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Debug for PasswordArray<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Display for PasswordArray<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl PasswordGeneratorIter {
+    /// The count of passwords which can be generated, or `None` if it is too large to be counted.
+    fn keyspace(&self) -> Option<u128> {
+        (self.length..=self.max_length).try_fold(0u128, |keyspace, length| {
+            keyspace.checked_add(self.keyspace_of_length(length)?)
+        })
+    }
+
+    /// The count of passwords of a length which can be generated, or `None` if it is too large to be counted.
+    fn keyspace_of_length(&self, length: usize) -> Option<u128> {
+        if self.exclude_offensive_words || !self.confusable_pairs.is_empty() {
+            let log2_count = self.log2_count_valid(length);
+
+            // `f64` counts exactly only below `2^53`
+            return if log2_count < 53.0 { Some(log2_count.exp2().round() as u128) } else { None };
+        }
+
+        let length = u32::try_from(length).ok()?;
+
+        if !self.strict {
+            return (self.total_len as u128).checked_pow(length);
+        }
+
+        // inclusion-exclusion over the kinds of characters which are missing
+        let mut keyspace = 0i128;
+
+        for missing in 0..(1usize << self.pool.len()) {
+            let missing_len: usize = self
+                .pool
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| missing & (1 << i) != 0)
+                .map(|(_, slice)| slice.len())
+                .sum();
+
+            let term =
+                i128::try_from((self.total_len - missing_len) as u128).ok()?.checked_pow(length)?;
+
+            if missing.count_ones() % 2 == 0 {
+                keyspace = keyspace.checked_add(term)?;
+            } else {
+                keyspace = keyspace.checked_sub(term)?;
+            }
+        }
+
+        Some(keyspace as u128)
+    }
+
+    /// Generate random passwords in parallel. Each worker thread uses its own independently seeded CSPRNG, and the passwords have the same distribution as the ones generated by the `generate` method.
+    pub fn par_generate(&self, count: usize) -> Vec<String> {
+        use rayon::prelude::*;
+
+        debug_assert_ne!(0, self.target_mask);
+
+        (0..count)
+            .into_par_iter()
+            .map_init(rand::rng, |rng, _| {
+                let mut password = String::with_capacity(self.max_length);
+
+                self.fill_valid_password(rng, &mut password);
+
+                password
+            })
+            .collect()
+    }
+
+    /// Generate distinct random passwords in parallel. Duplicated passwords are dropped and re-generated, so the result is uniformly picked from all combinations of distinct passwords.
+    pub fn par_generate_unique(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        if self.keyspace().is_some_and(|keyspace| keyspace < count as u128) {
+            return Err("The count of passwords exceeds the count of possible passwords.");
+        }
+
+        let mut set = HashSet::with_capacity(count);
+        let mut result = Vec::with_capacity(count);
+
+        while result.len() < count {
+            for password in self.par_generate(count - result.len()) {
+                if set.insert(password.clone()) {
+                    result.push(password);
+                }
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 impl Iterator for PasswordGeneratorIter {
@@ -151,10 +664,18 @@ impl Iterator for PasswordGeneratorIter {
 /// This struct can help you generate passwords.
 #[derive(Debug, Clone, PartialEq)]
 pub struct PasswordGenerator {
-    /// The length of the generated passwords.
+    /// The length of the generated passwords, or the minimum length if `max_length` is set.
     ///
     /// Default: `8`
     pub length:                     usize,
+    /// The maximum length of the generated passwords. If it is set, the length of each password is picked from `length` to `max_length` according to `length_distribution`.
+    ///
+    /// Default: `None`
+    pub max_length:                 Option<usize>,
+    /// How the length of each password is picked if `max_length` is set.
+    ///
+    /// Default: `LengthDistribution::Uniform`
+    pub length_distribution:        LengthDistribution,
     /// Passwords are allowed to, or must if the strict is true, contain a number or numbers.
     ///
     /// Default: `true`
@@ -183,14 +704,48 @@ pub struct PasswordGenerator {
     ///
     /// Default: `false`
     pub strict:                     bool,
+    /// Whether to exclude passwords containing offensive words, which are matched case-insensitively and with common leet substitutions, like `4` for `a` and `0` for `o`.
+    ///
+    /// Default: `false`
+    pub exclude_offensive_words:    bool,
+    /// Whether to exclude passwords containing adjacent pairs of characters which are easily confused in handwriting or common fonts, like `rn` for `m`. The pairs are listed in `confusable_pairs`.
+    ///
+    /// Default: `false`
+    pub transcription_safe:         bool,
+    /// The ordered pairs of adjacent characters excluded in the transcription-safe mode, or `None` for `PasswordGenerator::DEFAULT_CONFUSABLE_PAIRS`. The pairs containing characters out of the pool are ignored.
+    ///
+    /// Default: `None`
+    pub confusable_pairs:           Option<Cow<'static, [[char; 2]]>>,
 }
 
 impl PasswordGenerator {
+    /// The default pairs of adjacent characters excluded in the transcription-safe mode: `rn` and `nn` look like `m`, `vv` and `VV` look like `w` and `W`, `cl` and `cI` look like `d`, and the pairs of `1`, `l` and `I` look like each other, as do `0O` and `O0`.
+    pub const DEFAULT_CONFUSABLE_PAIRS: [[char; 2]; 16] = [
+        ['r', 'n'],
+        ['n', 'n'],
+        ['v', 'v'],
+        ['V', 'V'],
+        ['c', 'l'],
+        ['c', 'I'],
+        ['1', 'l'],
+        ['l', '1'],
+        ['1', 'I'],
+        ['I', '1'],
+        ['l', 'I'],
+        ['I', 'l'],
+        ['l', 'l'],
+        ['I', 'I'],
+        ['0', 'O'],
+        ['O', '0'],
+    ];
+
     /// Create a `PasswordGenerator` instance.
     ///
     /// ```rust,ignore
     /// PasswordGenerator {
     ///     length: 8,
+    ///     max_length: None,
+    ///     length_distribution: LengthDistribution::Uniform,
     ///     numbers: true,
     ///     lowercase_letters: true,
     ///     uppercase_letters: false,
@@ -198,11 +753,16 @@ impl PasswordGenerator {
     ///     spaces: false,
     ///     exclude_similar_characters: false,
     ///     strict: false,
+    ///     exclude_offensive_words: false,
+    ///     transcription_safe: false,
+    ///     confusable_pairs: None,
     /// }
     /// ```
     pub const fn new() -> PasswordGenerator {
         PasswordGenerator {
             length:                     8,
+            max_length:                 None,
+            length_distribution:        LengthDistribution::Uniform,
             numbers:                    true,
             lowercase_letters:          true,
             uppercase_letters:          false,
@@ -210,12 +770,34 @@ impl PasswordGenerator {
             spaces:                     false,
             exclude_similar_characters: false,
             strict:                     false,
+            exclude_offensive_words:    false,
+            transcription_safe:         false,
+            confusable_pairs:           None,
         }
     }
 
-    /// The length of the generated passwords.
+    /// The length of the generated passwords. It replaces the length range if any.
     pub const fn length(mut self, length: usize) -> PasswordGenerator {
         self.length = length;
+        self.max_length = None;
+
+        self
+    }
+
+    /// The range of the length of the generated passwords, like `12..=16`.
+    pub const fn length_range(mut self, length_range: RangeInclusive<usize>) -> PasswordGenerator {
+        self.length = *length_range.start();
+        self.max_length = Some(*length_range.end());
+
+        self
+    }
+
+    /// How the length of each password is picked from the length range.
+    pub const fn length_distribution(
+        mut self,
+        length_distribution: LengthDistribution,
+    ) -> PasswordGenerator {
+        self.length_distribution = length_distribution;
 
         self
     }
@@ -271,6 +853,49 @@ impl PasswordGenerator {
 
         self
     }
+
+    /// Whether to exclude passwords containing offensive words, which are matched case-insensitively and with common leet substitutions, like `4` for `a` and `0` for `o`.
+    pub const fn exclude_offensive_words(
+        mut self,
+        exclude_offensive_words: bool,
+    ) -> PasswordGenerator {
+        self.exclude_offensive_words = exclude_offensive_words;
+
+        self
+    }
+
+    /// Whether to exclude passwords containing adjacent pairs of characters which are easily confused in handwriting or common fonts, like `rn` for `m`.
+    pub const fn transcription_safe(mut self, transcription_safe: bool) -> PasswordGenerator {
+        self.transcription_safe = transcription_safe;
+
+        self
+    }
+
+    /// The ordered pairs of adjacent characters excluded in the transcription-safe mode.
+    pub fn confusable_pairs(mut self, confusable_pairs: &[[char; 2]]) -> PasswordGenerator {
+        self.set_confusable_pairs(confusable_pairs.to_vec());
+
+        self
+    }
+}
+
+impl PasswordGenerator {
+    /// The confusable pairs in effect.
+    #[inline]
+    fn effective_confusable_pairs(&self) -> &[[char; 2]] {
+        self.confusable_pairs.as_deref().unwrap_or(&PasswordGenerator::DEFAULT_CONFUSABLE_PAIRS)
+    }
+
+    /// Set the confusable pairs, keeping `None` for the default ones.
+    #[inline]
+    fn set_confusable_pairs(&mut self, confusable_pairs: Vec<[char; 2]>) {
+        self.confusable_pairs =
+            if confusable_pairs[..] == PasswordGenerator::DEFAULT_CONFUSABLE_PAIRS {
+                None
+            } else {
+                Some(Cow::Owned(confusable_pairs))
+            };
+    }
 }
 
 impl PasswordGenerator {
@@ -296,6 +921,14 @@ impl PasswordGenerator {
             return Err("The length of passwords cannot be 0.");
         }
 
+        let max_length = self.max_length.unwrap_or(self.length);
+
+        if max_length < self.length {
+            return Err(
+                "The maximum length of passwords cannot be smaller than the minimum length."
+            );
+        }
+
         let mut pool: Vec<&[char]> = Vec::new();
 
         let mut sections_count = 0;
@@ -361,15 +994,78 @@ impl PasswordGenerator {
         {
             Err("You need to enable at least one kind of characters.")
         } else if self.strict && self.length < sections_count {
+            // the minimum length needs to fit every kind of characters
             Err("The length of passwords is too short.")
         } else {
-            Ok(PasswordGeneratorIter {
+            let mut iter = PasswordGeneratorIter {
                 total_len: pool.iter().map(|slice| slice.len()).sum(),
                 pool,
                 length: self.length,
+                max_length,
+                length_distribution: self.length_distribution,
+                length_weights: Vec::new(),
                 target_mask,
                 strict: self.strict,
-            })
+                exclude_offensive_words: self.exclude_offensive_words,
+                confusable_pairs: Vec::new(),
+                sampler: None,
+            };
+
+            if self.transcription_safe {
+                let pool = iter.pool.concat();
+
+                iter.confusable_pairs = self
+                    .effective_confusable_pairs()
+                    .iter()
+                    .filter(|pair| pair.iter().all(|c| pool.contains(c)))
+                    .copied()
+                    .collect();
+
+                if (self.length..=max_length)
+                    .any(|length| iter.entropy_of_length(length) == f64::NEG_INFINITY)
+                {
+                    return Err("No password satisfies the confusable pairs.");
+                }
+            }
+
+            if iter.exclude_offensive_words || !iter.confusable_pairs.is_empty() {
+                let pool = iter.pool.concat();
+                let firsts = iter.confusable_firsts();
+
+                let sampler = CleanSampler::new(
+                    iter.exclude_offensive_words,
+                    max_length,
+                    firsts.len() + 1,
+                    0,
+                    false,
+                    |_, class| iter.class_choices(&pool, &firsts, class),
+                );
+
+                iter.sampler = Some(sampler);
+            }
+
+            if max_length > self.length
+                && self.length_distribution == LengthDistribution::Proportional
+            {
+                let entropies: Vec<f64> = (self.length..=max_length)
+                    .map(|length| iter.entropy_of_length(length))
+                    .collect();
+
+                let total = log2_sum_exp2(entropies.iter().copied());
+
+                let mut cumulative = 0f64;
+
+                iter.length_weights = entropies
+                    .into_iter()
+                    .map(|entropy| {
+                        cumulative += (entropy - total).exp2();
+
+                        cumulative
+                    })
+                    .collect();
+            }
+
+            Ok(iter)
         }
     }
 }
@@ -380,3 +1076,460 @@ impl Default for PasswordGenerator {
         PasswordGenerator::new()
     }
 }
+
+/// An error which can be returned when parsing a compact spec of `PasswordGenerator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePasswordGeneratorError {
+    /// A token is empty, e.g. there are two commas in a row.
+    EmptyToken,
+    /// A token is not recognized.
+    UnknownToken(String),
+    /// A flag token appears more than once.
+    DuplicatedToken(String),
+    /// The value of `len` is not a non-negative integer.
+    InvalidLength(String),
+    /// `len` is specified more than once with different values.
+    ConflictingLengths(usize, usize),
+    /// `len` is specified more than once with different ranges, like `len=12-16` and `len=12`.
+    ConflictingLengthRanges(String, String),
+    /// The value of `pairs` is not a list of pairs of characters.
+    InvalidConfusablePairs(String),
+}
+
+impl Display for ParsePasswordGeneratorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePasswordGeneratorError::EmptyToken => f.write_str("a token is empty"),
+            ParsePasswordGeneratorError::UnknownToken(token) => {
+                write!(
+                    f,
+                    "unknown token `{token}`, expected `len=<n>`, `len=<min>-<max>`, \
+                     `len-proportional`, `digits`, `lower`, `upper`, `symbols`, `spaces`, \
+                     `no-similar`, `strict`, `no-offensive`, `transcription-safe` or \
+                     `pairs=<pairs>`"
+                )
+            },
+            ParsePasswordGeneratorError::DuplicatedToken(token) => {
+                write!(f, "the token `{token}` appears more than once")
+            },
+            ParsePasswordGeneratorError::InvalidLength(value) => {
+                write!(f, "`{value}` is not a valid length")
+            },
+            ParsePasswordGeneratorError::ConflictingLengths(a, b) => {
+                write!(f, "the length is specified as both {a} and {b}")
+            },
+            ParsePasswordGeneratorError::ConflictingLengthRanges(a, b) => {
+                write!(f, "the length is specified as both {a} and {b}")
+            },
+            ParsePasswordGeneratorError::InvalidConfusablePairs(value) => {
+                write!(f, "`{value}` is not a valid list of confusable pairs")
+            },
+        }
+    }
+}
+
+impl Error for ParsePasswordGeneratorError {}
+
+/// Write a pair of characters for the spec. Characters other than ASCII letters and digits are percent-encoded in UTF-8, like `%2C` for `,`.
+fn write_pair(f: &mut Formatter<'_>, pair: &[char; 2]) -> fmt::Result {
+    for &c in pair {
+        if c.is_ascii_alphanumeric() {
+            write!(f, "{c}")?;
+        } else {
+            for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                write!(f, "%{b:02X}")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a pair of characters written by `write_pair`.
+fn parse_pair(s: &str) -> Option<[char; 2]> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(b);
+        }
+    }
+
+    let mut chars = std::str::from_utf8(&bytes).ok()?.chars();
+
+    let pair = [chars.next()?, chars.next()?];
+
+    if chars.next().is_some() { None } else { Some(pair) }
+}
+
+impl FromStr for PasswordGenerator {
+    type Err = ParsePasswordGeneratorError;
+
+    /// Parse a compact spec, like `len=16,upper,lower,digits,symbols,no-similar,strict`.
+    ///
+    /// Tokens are separated by commas. `len=<n>` sets the length, which is `8` if absent, and `len=<min>-<max>` sets a length range. `len-proportional` picks the length of each password from the range in proportion to its count of passwords. `digits`, `lower`, `upper`, `symbols` and `spaces` enable the kinds of characters; the absent ones are disabled. `no-similar` excludes similar characters, `strict` makes the rules strict, `no-offensive` excludes offensive words and `transcription-safe` excludes the confusable pairs. `pairs=<pairs>` replaces the default confusable pairs with a list separated by `+`, like `pairs=rn+vv`, where characters other than ASCII letters and digits are percent-encoded in UTF-8, like `%2C` for `,`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut generator = PasswordGenerator {
+            length:                     8,
+            max_length:                 None,
+            length_distribution:        LengthDistribution::Uniform,
+            numbers:                    false,
+            lowercase_letters:          false,
+            uppercase_letters:          false,
+            symbols:                    false,
+            spaces:                     false,
+            exclude_similar_characters: false,
+            strict:                     false,
+            exclude_offensive_words:    false,
+            transcription_safe:         false,
+            confusable_pairs:           None,
+        };
+
+        let mut length = None;
+        let mut pairs = None;
+
+        for token in s.split(',') {
+            let token = token.trim();
+
+            if token.is_empty() {
+                return Err(ParsePasswordGeneratorError::EmptyToken);
+            }
+
+            if let Some(value) = token.strip_prefix("len=") {
+                let value = value.trim();
+
+                let parse = |s: &str| {
+                    s.trim()
+                        .parse()
+                        .map_err(|_| ParsePasswordGeneratorError::InvalidLength(value.to_string()))
+                };
+
+                let n = match value.split_once('-') {
+                    Some((min, max)) => (parse(min)?, Some(parse(max)?)),
+                    None => (parse(value)?, None),
+                };
+
+                match length {
+                    Some((a, None)) if n == (a, None) => (),
+                    Some((a, None)) if n.1.is_none() => {
+                        return Err(ParsePasswordGeneratorError::ConflictingLengths(a, n.0));
+                    },
+                    Some(old) if old != n => {
+                        let format = |(min, max): (usize, Option<usize>)| match max {
+                            Some(max) => format!("{min}-{max}"),
+                            None => min.to_string(),
+                        };
+
+                        return Err(ParsePasswordGeneratorError::ConflictingLengthRanges(
+                            format(old),
+                            format(n),
+                        ));
+                    },
+                    _ => length = Some(n),
+                }
+
+                continue;
+            }
+
+            if let Some(value) = token.strip_prefix("pairs=") {
+                if pairs.is_some() {
+                    return Err(ParsePasswordGeneratorError::DuplicatedToken("pairs".to_string()));
+                }
+
+                let value = value.trim();
+
+                let parsed = if value.is_empty() {
+                    Some(Vec::new())
+                } else {
+                    value.split('+').map(parse_pair).collect()
+                };
+
+                pairs = Some(parsed.ok_or_else(|| {
+                    ParsePasswordGeneratorError::InvalidConfusablePairs(value.to_string())
+                })?);
+
+                continue;
+            }
+
+            if token == "len-proportional" {
+                if generator.length_distribution == LengthDistribution::Proportional {
+                    return Err(ParsePasswordGeneratorError::DuplicatedToken(token.to_string()));
+                }
+
+                generator.length_distribution = LengthDistribution::Proportional;
+
+                continue;
+            }
+
+            let flag = match token {
+                "digits" => &mut generator.numbers,
+                "lower" => &mut generator.lowercase_letters,
+                "upper" => &mut generator.uppercase_letters,
+                "symbols" => &mut generator.symbols,
+                "spaces" => &mut generator.spaces,
+                "no-similar" => &mut generator.exclude_similar_characters,
+                "strict" => &mut generator.strict,
+                "no-offensive" => &mut generator.exclude_offensive_words,
+                "transcription-safe" => &mut generator.transcription_safe,
+                _ => return Err(ParsePasswordGeneratorError::UnknownToken(token.to_string())),
+            };
+
+            if *flag {
+                return Err(ParsePasswordGeneratorError::DuplicatedToken(token.to_string()));
+            }
+
+            *flag = true;
+        }
+
+        if let Some((length, max_length)) = length {
+            generator.length = length;
+            generator.max_length = max_length;
+        }
+
+        if let Some(pairs) = pairs {
+            generator.set_confusable_pairs(pairs);
+        }
+
+        Ok(generator)
+    }
+}
+
+impl Display for PasswordGenerator {
+    /// Format the compact spec, like `len=16,digits,lower,upper,symbols,no-similar,strict`, which can be parsed back to the same `PasswordGenerator`. `pairs` is written only if `confusable_pairs` is customized.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "len={}", self.length)?;
+
+        if let Some(max_length) = self.max_length {
+            write!(f, "-{max_length}")?;
+        }
+
+        if self.length_distribution == LengthDistribution::Proportional {
+            f.write_str(",len-proportional")?;
+        }
+
+        for (enabled, token) in [
+            (self.numbers, "digits"),
+            (self.lowercase_letters, "lower"),
+            (self.uppercase_letters, "upper"),
+            (self.symbols, "symbols"),
+            (self.spaces, "spaces"),
+            (self.exclude_similar_characters, "no-similar"),
+            (self.strict, "strict"),
+            (self.exclude_offensive_words, "no-offensive"),
+            (self.transcription_safe, "transcription-safe"),
+        ] {
+            if enabled {
+                f.write_str(",")?;
+                f.write_str(token)?;
+            }
+        }
+
+        let confusable_pairs = self.effective_confusable_pairs();
+
+        if confusable_pairs != PasswordGenerator::DEFAULT_CONFUSABLE_PAIRS {
+            f.write_str(",pairs=")?;
+
+            for (i, pair) in confusable_pairs.iter().enumerate() {
+                if i > 0 {
+                    f.write_str("+")?;
+                }
+
+                write_pair(f, pair)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PasswordGenerator {
+    /// Generate a password from a user-supplied source of entropy, like dice rolls, instead of the CSPRNG. The characters, and the length if a length range is set, are picked without bias, and the passwords which do not satisfy the strict rules are rejected and picked again from the following input. If offensive words or confusable pairs are excluded, each character is picked from a number of 53 bits, so that it can be picked in proportion to the count of valid passwords, which takes much more input than picking from the pool.
+    ///
+    /// If the input runs out, nothing is consumed, and the error reports the count of additional rolls or bytes needed to finish if none of them is rejected, assuming the minimum length if the length has not been picked yet.
+    pub fn generate_with_entropy(
+        &self,
+        entropy: &mut ExternalEntropy,
+    ) -> Result<String, ExternalEntropyError> {
+        self.try_iter()?.generate_with_entropy(entropy)
+    }
+}
+
+impl PasswordGeneratorIter {
+    /// Generate a password from a user-supplied source of entropy, like dice rolls, instead of the CSPRNG. See `PasswordGenerator::generate_with_entropy`.
+    #[inline]
+    pub fn generate_with_entropy(
+        &self,
+        entropy: &mut ExternalEntropy,
+    ) -> Result<String, ExternalEntropyError> {
+        entropy.generate(|entropy| self.read_password(entropy))
+    }
+
+    fn read_password(&self, entropy: &mut ExternalEntropy) -> Result<String, ExternalEntropyError> {
+        let digits_per_char = entropy.digits_per_pick(self.pick_range());
+
+        let length = if self.length == self.max_length {
+            self.length
+        } else {
+            let picked = match self.length_distribution {
+                LengthDistribution::Uniform => {
+                    entropy.pick(self.max_length - self.length + 1).map(|index| self.length + index)
+                },
+                LengthDistribution::Proportional => {
+                    entropy.pick(1 << 53).map(|r| self.length_at(r as f64 / (1u64 << 53) as f64))
+                },
+            };
+
+            picked.map_err(|missing| ExternalEntropyError::Exhausted {
+                needed: missing + self.length * digits_per_char,
+            })?
+        };
+
+        let mut password = String::with_capacity(length);
+
+        loop {
+            password.clear();
+
+            let mask = self.fill_password_with(
+                length,
+                |i, n| entropy.pick_nth(n, i, length),
+                |c| password.push(c),
+            )?;
+
+            if self.is_valid(mask) {
+                return Ok(password);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl PasswordGenerator {
+    /// Derive a password from a master secret, a site, a login and a counter, statelessly. The same inputs and rules always yield the same password, and changing the counter rotates it.
+    ///
+    /// The inputs are stretched by Argon2id (version 0x13, 19 MiB of memory, 2 iterations, 1 lane) into a seed of ChaCha20, which is then used to pick characters by rejection sampling, under the rules of this generator. These parameters are fixed, so that derived passwords stay the same across versions of this crate.
+    pub fn derive<M: ?Sized + AsRef<[u8]>, S: AsRef<str>, L: AsRef<str>>(
+        &self,
+        master_secret: &M,
+        site: S,
+        login: L,
+        counter: u32,
+    ) -> Result<String, &'static str> {
+        use argon2::{Algorithm, Argon2, Params, Version};
+        use rand::{SeedableRng, rngs::ChaCha20Rng};
+
+        #[inline]
+        fn uniform_index<R: Rng + ?Sized>(rng: &mut R, len: usize) -> usize {
+            let Ok(len) = u32::try_from(len) else {
+                let len = len as u64;
+                let zone = u64::MAX - u64::MAX % len;
+
+                loop {
+                    let v = rng.next_u64();
+
+                    if v < zone {
+                        return (v % len) as usize;
+                    }
+                }
+            };
+
+            let zone = u32::MAX - u32::MAX % len;
+
+            loop {
+                let v = rng.next_u32();
+
+                if v < zone {
+                    return (v % len) as usize;
+                }
+            }
+        }
+
+        let iter = self.try_iter()?;
+
+        let master_secret = master_secret.as_ref();
+
+        if master_secret.is_empty() {
+            return Err("The master secret is empty.");
+        }
+
+        let site = site.as_ref().as_bytes();
+        let login = login.as_ref().as_bytes();
+
+        let mut salt = Vec::with_capacity(12 + site.len() + login.len());
+
+        salt.extend_from_slice(&(site.len() as u32).to_be_bytes());
+        salt.extend_from_slice(site);
+        salt.extend_from_slice(&(login.len() as u32).to_be_bytes());
+        salt.extend_from_slice(login);
+        salt.extend_from_slice(&counter.to_be_bytes());
+
+        let params = Params::new(19 * 1024, 2, 1, Some(32))
+            .map_err(|_| "The parameters of Argon2 are invalid.")?;
+
+        let mut seed = [0u8; 32];
+
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(master_secret, &salt, &mut seed)
+            .map_err(|_| "Failed to stretch the master secret.")?;
+
+        let mut rng = ChaCha20Rng::from_seed(seed);
+
+        // no randomness is consumed for a fixed length, so that derived passwords stay the same
+        let length = if iter.length == iter.max_length {
+            iter.length
+        } else {
+            match iter.length_distribution {
+                LengthDistribution::Uniform => {
+                    iter.length + uniform_index(&mut rng, iter.max_length - iter.length + 1)
+                },
+                LengthDistribution::Proportional => {
+                    iter.length_at((rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64)
+                },
+            }
+        };
+
+        let mut password = String::with_capacity(length);
+
+        loop {
+            password.clear();
+
+            let Ok(mask) = iter.fill_password_with::<Infallible, _, _>(
+                length,
+                |_, n| Ok(uniform_index(&mut rng, n)),
+                |c| password.push(c),
+            );
+
+            if iter.is_valid(mask) {
+                break;
+            }
+        }
+
+        Ok(password)
+    }
+
+    /// Generate a random password and hash it with bcrypt in one call. The plaintext, for one-time display, and the hash in Modular Crypt Format are returned as a tuple.
+    ///
+    /// The password is hashed with a null terminator appended, like `hasher::get_password_with_null_terminated_byte` does, so it can be identified by `hasher::identify_bcrypt_format` with `"<password>\0"`. To fit the 72-byte limit of bcrypt, the maximum length of passwords cannot be bigger than 71.
+    pub fn generate_hashed(&self, cost: u8) -> Result<(String, String), &'static str> {
+        use crate::hasher;
+
+        if self.max_length.unwrap_or(self.length).max(self.length) > 71 {
+            return Err("The length of passwords cannot be bigger than 71 to fit bcrypt.");
+        }
+
+        let password = self.generate_one()?;
+
+        let mut bytes = Vec::with_capacity(password.len() + 1);
+
+        bytes.extend_from_slice(password.as_bytes());
+        bytes.push(0);
+
+        let hashed = hasher::bcrypt_format(cost, &hasher::gen_salt(), &bytes)?;
+
+        Ok((password, hashed))
+    }
+}
@@ -0,0 +1,345 @@
+use super::PasswordGeneratorIter;
+
+/// The false alarm rate of each test over a whole sample, `2^-20`, as recommended by NIST SP 800-90B.
+const ALPHA: f64 = 1.0 / 1_048_576.0;
+
+/// The window size of the adaptive proportion test for non-binary sources.
+const WINDOW_SIZE: usize = 512;
+
+/// The result of a chi-square test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquareTest {
+    /// The chi-square statistic.
+    pub statistic:          f64,
+    /// The degrees of freedom.
+    pub degrees_of_freedom: usize,
+    /// The probability of a statistic at least this large if the null hypothesis holds.
+    pub p_value:            f64,
+}
+
+impl ChiSquareTest {
+    fn new(statistic: f64, degrees_of_freedom: usize) -> ChiSquareTest {
+        let p_value = if degrees_of_freedom == 0 {
+            1.0
+        } else {
+            gamma_q(degrees_of_freedom as f64 / 2.0, statistic / 2.0)
+        };
+
+        ChiSquareTest {
+            statistic,
+            degrees_of_freedom,
+            p_value,
+        }
+    }
+
+    /// Whether the p-value is not smaller than `2^-20`.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.p_value >= ALPHA
+    }
+}
+
+/// The result of a test which fails if an observed count reaches a cutoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CutoffTest {
+    /// The largest count observed.
+    pub observed: usize,
+    /// The count at which the test fails.
+    pub cutoff:   usize,
+}
+
+impl CutoffTest {
+    /// Whether the observed count is smaller than the cutoff.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.observed < self.cutoff
+    }
+}
+
+/// The report of `PasswordGeneratorIter::self_test`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfTestReport {
+    /// The count of sampled passwords.
+    pub samples:             usize,
+    /// The count of characters in the pool.
+    pub pool_size:           usize,
+    /// The chi-square goodness-of-fit test of the frequencies of the characters against the uniform distribution over the pool.
+    pub uniformity:          ChiSquareTest,
+    /// The chi-square test of independence between the positions and the characters.
+    pub positional_bias:     ChiSquareTest,
+    /// The repetition count test of NIST SP 800-90B, section 4.4.1, which observes the longest run of an identical character in the sampled stream. The cutoff is raised for the length of the stream, so that the false alarm rate applies to the whole stream instead of each character.
+    pub repetition_count:    CutoffTest,
+    /// The adaptive proportion test of NIST SP 800-90B, section 4.4.2, which observes the largest count of the first character of a 512-character window within the window. The cutoff is raised for the count of windows, so that the false alarm rate applies to the whole stream instead of each window.
+    pub adaptive_proportion: CutoffTest,
+}
+
+impl SelfTestReport {
+    /// Whether all tests passed.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.uniformity.passed()
+            && self.positional_bias.passed()
+            && self.repetition_count.passed()
+            && self.adaptive_proportion.passed()
+    }
+}
+
+impl PasswordGeneratorIter {
+    /// Sample passwords and run statistical health tests on the picked characters, to check that the pool and the sampling are not biased.
+    ///
+    /// The characters are sampled uniformly from the pool, like the generated passwords, but without the strict rules, the offensive word filter and the transcription-safe mode, because they make the distribution of characters deliberately non-uniform. Each test has a false alarm rate of at most `2^-20` over the whole sample. For meaningful chi-square tests, `samples` should be at least 5 times the count of characters in the pool.
+    pub fn self_test(&self, samples: usize) -> Result<SelfTestReport, &'static str> {
+        if samples == 0 {
+            return Err("The count of samples cannot be 0.");
+        }
+
+        let pool = self.pool.concat();
+        let pool_size = pool.len();
+
+        // all characters in the pool are ASCII
+        let mut indexes = [usize::MAX; 128];
+
+        for (i, &c) in pool.iter().enumerate() {
+            indexes[c as usize] = i;
+        }
+
+        let mut rng = rand::rng();
+        let mut password = String::with_capacity(self.max_length);
+
+        let mut counts = vec![0u64; self.max_length * pool_size];
+        let mut row_totals = vec![0u64; self.max_length];
+        let mut stream = Vec::with_capacity(samples * self.max_length);
+
+        for _ in 0..samples {
+            let length = self.pick_length(&mut rng);
+
+            self.fill_unfiltered_password(&mut rng, length, &mut password);
+
+            for (position, b) in password.bytes().enumerate() {
+                let index = indexes[b as usize];
+
+                counts[position * pool_size + index] += 1;
+                row_totals[position] += 1;
+                stream.push(index);
+            }
+        }
+
+        let total = stream.len() as f64;
+
+        // uniformity
+        let expected = total / pool_size as f64;
+
+        let statistic = (0..pool_size)
+            .map(|index| {
+                let observed: u64 =
+                    (0..self.max_length).map(|position| counts[position * pool_size + index]).sum();
+
+                (observed as f64 - expected).powi(2) / expected
+            })
+            .sum();
+
+        let uniformity = ChiSquareTest::new(statistic, pool_size - 1);
+
+        // positional bias, as a contingency table of positions and characters
+        let column_totals: Vec<f64> = (0..pool_size)
+            .map(|index| {
+                (0..self.max_length)
+                    .map(|position| counts[position * pool_size + index] as f64)
+                    .sum()
+            })
+            .collect();
+
+        let mut statistic = 0f64;
+
+        for (position, &row_total) in row_totals.iter().enumerate() {
+            for (index, &column_total) in column_totals.iter().enumerate() {
+                let expected = row_total as f64 * column_total / total;
+
+                if expected > 0.0 {
+                    statistic +=
+                        (counts[position * pool_size + index] as f64 - expected).powi(2) / expected;
+                }
+            }
+        }
+
+        let non_empty_rows = row_totals.iter().filter(|&&total| total > 0).count();
+        let non_empty_columns = column_totals.iter().filter(|&&total| total > 0.0).count();
+
+        let positional_bias = ChiSquareTest::new(
+            statistic,
+            non_empty_rows.saturating_sub(1) * non_empty_columns.saturating_sub(1),
+        );
+
+        // the min-entropy of a character, assuming the sampling is uniform
+        let h = (pool_size as f64).log2();
+
+        // repetition count test
+        let mut max_run = 1;
+        let mut run = 1;
+
+        for w in stream.windows(2) {
+            if w[0] == w[1] {
+                run += 1;
+                max_run = max_run.max(run);
+            } else {
+                run = 1;
+            }
+        }
+
+        // a run can start at any character
+        let repetition_count = CutoffTest {
+            observed: max_run,
+            cutoff:   if pool_size > 1 {
+                1 + ((total.log2() - ALPHA.log2()) / h).ceil() as usize
+            } else {
+                usize::MAX
+            },
+        };
+
+        // adaptive proportion test over the complete windows
+        let windows = (stream.len() / WINDOW_SIZE).max(1);
+
+        let observed = stream
+            .chunks_exact(WINDOW_SIZE)
+            .map(|window| window.iter().filter(|&&index| index == window[0]).count())
+            .max()
+            .unwrap_or(0);
+
+        let adaptive_proportion = CutoffTest {
+            observed,
+            cutoff: if pool_size > 1 {
+                1 + critical_binomial(
+                    WINDOW_SIZE,
+                    1.0 / pool_size as f64,
+                    1.0 - ALPHA / windows as f64,
+                )
+            } else {
+                usize::MAX
+            },
+        };
+
+        Ok(SelfTestReport {
+            samples,
+            pool_size,
+            uniformity,
+            positional_bias,
+            repetition_count,
+            adaptive_proportion,
+        })
+    }
+}
+
+/// The natural logarithm of the gamma function, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+
+    let mut series = 1.000_000_000_190_015;
+    let mut y = x;
+
+    for c in COEFFICIENTS {
+        y += 1.0;
+        series += c / y;
+    }
+
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+/// The regularized upper incomplete gamma function `Q(a, x)`.
+fn gamma_q(a: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 100_000;
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    let ln_prefix = -x + a * x.ln() - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // the series of P(a, x)
+        let mut ap = a;
+        let mut delta = 1.0 / a;
+        let mut sum = delta;
+
+        for _ in 0..MAX_ITERATIONS {
+            ap += 1.0;
+            delta *= x / ap;
+            sum += delta;
+
+            if delta.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+
+        (1.0 - sum * ln_prefix.exp()).clamp(0.0, 1.0)
+    } else {
+        // the continued fraction of Q(a, x), by the modified Lentz's method
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+
+        for i in 1..MAX_ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+
+            b += 2.0;
+            d = an * d + b;
+
+            if d.abs() < TINY {
+                d = TINY;
+            }
+
+            c = b + an / c;
+
+            if c.abs() < TINY {
+                c = TINY;
+            }
+
+            d = 1.0 / d;
+
+            let delta = d * c;
+
+            h *= delta;
+
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+
+        (ln_prefix.exp() * h).clamp(0.0, 1.0)
+    }
+}
+
+/// The smallest `k` such that the probability of at most `k` successes in `n` trials of probability `p` is at least `probability`.
+fn critical_binomial(n: usize, p: f64, probability: f64) -> usize {
+    let ln_p = p.ln();
+    let ln_q = (1.0 - p).ln();
+
+    let mut ln_choose = 0f64;
+    let mut cumulative = 0f64;
+
+    for k in 0..=n {
+        if k > 0 {
+            ln_choose += ((n - k + 1) as f64).ln() - (k as f64).ln();
+        }
+
+        cumulative += (ln_choose + k as f64 * ln_p + (n - k) as f64 * ln_q).exp();
+
+        if cumulative >= probability {
+            return k;
+        }
+    }
+
+    n
+}
@@ -0,0 +1,140 @@
+use crate::PasswordGenerator;
+
+/// This struct can help you generate long passwords which are displayed in groups, like `k7Qm-p2Xs-9vTn-Hb4w`, and normalize what a person types back in.
+///
+/// The separator is guaranteed not to be in the pool of the generator, and the pool cannot contain whitespace, so `normalize_input` recovers exactly the generated password by stripping separators and whitespace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupedPasswordGenerator {
+    /// The generator picking the characters. Its pool cannot contain the separator or spaces.
+    ///
+    /// Default: `PasswordGenerator::new().length(16).uppercase_letters(true)`
+    pub generator:  PasswordGenerator,
+    /// The count of characters in a group. The last group is shorter if the length of a password is not a multiple of it.
+    ///
+    /// Default: `4`
+    pub group_size: usize,
+    /// The separator between groups.
+    ///
+    /// Default: `'-'`
+    pub separator:  char,
+}
+
+impl GroupedPasswordGenerator {
+    /// Create a `GroupedPasswordGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// GroupedPasswordGenerator {
+    ///     generator: PasswordGenerator::new().length(16).uppercase_letters(true),
+    ///     group_size: 4,
+    ///     separator: '-',
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> GroupedPasswordGenerator {
+        GroupedPasswordGenerator {
+            generator:  PasswordGenerator::new().length(16).uppercase_letters(true),
+            group_size: 4,
+            separator:  '-',
+        }
+    }
+
+    /// The generator picking the characters.
+    #[inline]
+    pub fn generator(mut self, generator: PasswordGenerator) -> GroupedPasswordGenerator {
+        self.generator = generator;
+
+        self
+    }
+
+    /// The count of characters in a group.
+    #[inline]
+    pub const fn group_size(mut self, group_size: usize) -> GroupedPasswordGenerator {
+        self.group_size = group_size;
+
+        self
+    }
+
+    /// The separator between groups.
+    #[inline]
+    pub const fn separator(mut self, separator: char) -> GroupedPasswordGenerator {
+        self.separator = separator;
+
+        self
+    }
+}
+
+impl GroupedPasswordGenerator {
+    fn check(&self) -> Result<(), &'static str> {
+        if self.group_size == 0 {
+            return Err("The size of groups cannot be 0.");
+        }
+
+        let iter = self.generator.try_iter()?;
+
+        let mut pool = iter.pool.iter().flat_map(|slice| slice.iter());
+
+        if pool.clone().any(|c| c.is_whitespace()) {
+            return Err("Grouped passwords cannot contain spaces, which are stripped from input.");
+        }
+
+        if pool.any(|&c| c == self.separator) {
+            return Err("The separator cannot be in the pool of the generator.");
+        }
+
+        Ok(())
+    }
+
+    /// Split a password into groups joined by the separator.
+    pub fn format<S: AsRef<str>>(&self, password: S) -> String {
+        let mut formatted = String::new();
+
+        for (i, c) in password.as_ref().chars().enumerate() {
+            if i > 0 && i % self.group_size.max(1) == 0 {
+                formatted.push(self.separator);
+            }
+
+            formatted.push(c);
+        }
+
+        formatted
+    }
+
+    /// Recover a password from what a person types back in, by stripping separators and whitespace.
+    #[inline]
+    pub fn normalize_input<S: AsRef<str>>(&self, input: S) -> String {
+        input.as_ref().chars().filter(|&c| c != self.separator && !c.is_whitespace()).collect()
+    }
+
+    /// The entropy of the generated passwords, in bits. Grouping adds no entropy.
+    #[inline]
+    pub fn entropy(&self) -> Result<f64, &'static str> {
+        self.check()?;
+
+        Ok(self.generator.try_iter()?.entropy())
+    }
+
+    /// Generate random passwords, formatted in groups.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        self.check()?;
+
+        Ok(self
+            .generator
+            .generate(count)?
+            .into_iter()
+            .map(|password| self.format(password))
+            .collect())
+    }
+
+    /// Generate a random password, formatted in groups.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+}
+
+impl Default for GroupedPasswordGenerator {
+    #[inline]
+    fn default() -> GroupedPasswordGenerator {
+        GroupedPasswordGenerator::new()
+    }
+}
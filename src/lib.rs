@@ -7,10 +7,12 @@ This crate provides useful tools to generate multiple readable passwords, as wel
 `PasswordGenerator` can be used for generating passwords which consist optional numbers, lowercase letters, uppercase letters, symbols and spaces.
 
 ```rust
-use passwords::PasswordGenerator;
+use passwords::{LengthDistribution, PasswordGenerator};
 
 let pg = PasswordGenerator {
        length: 8,
+       max_length: None,
+       length_distribution: LengthDistribution::Uniform,
        numbers: true,
        lowercase_letters: true,
        uppercase_letters: true,
@@ -18,12 +20,17 @@ let pg = PasswordGenerator {
        spaces: true,
        exclude_similar_characters: false,
        strict: true,
+       exclude_offensive_words: false,
+       transcription_safe: false,
+       confusable_pairs: None,
    };
 
 println!("{}", pg.generate_one().unwrap());
 println!("{:?}", pg.generate(5).unwrap());
 ```
 
+Since 4.0.0, `PasswordGenerator` has the `max_length`, `length_distribution`, `exclude_offensive_words`, `transcription_safe` and `confusable_pairs` fields, so struct literals written for 3.x need to be updated. A struct literal can end with `..PasswordGenerator::new()` to take the defaults of the fields which are not written.
+
 It also has a fluent interface.
 
 ```rust
@@ -35,6 +42,16 @@ println!("{}", pg.generate_one().unwrap());
 println!("{:?}", pg.generate(5).unwrap());
 ```
 
+It can also be parsed from, and formatted to, a compact spec, which is handy for environment variables and CLI flags.
+
+```rust
+use passwords::PasswordGenerator;
+
+let pg: PasswordGenerator = "len=16,upper,lower,digits,symbols,no-similar,strict".parse().unwrap();
+
+assert_eq!("len=16,digits,lower,upper,symbols,no-similar,strict", pg.to_string());
+```
+
 The `generate` method has been optimized for multiple generation. Don't reuse the `generate_one` method to generate multiple passwords. If the count of passwords can't be determined, use the `try_iter` method to create a `PasswordGeneratorIter` instance which implements the `Iterator` trait and can re-generate passwords more efficiently.
 
 ```rust
@@ -55,6 +72,430 @@ println!("{}", pgi.next().unwrap());
 println!("{}", pgi.next().unwrap());
 ```
 
+To write a large number of passwords into a file or a pipe with constant memory usage, use the `generate_into` method.
+
+```rust
+use passwords::PasswordGenerator;
+
+let pgi = PasswordGenerator::new().try_iter().unwrap();
+
+pgi.generate_into(5, std::io::stdout().lock(), "\n").unwrap();
+```
+
+In hot paths, the `fill_string`, `fill_bytes` and `generate_array` methods of `PasswordGeneratorIter` can generate passwords into a reused `String`, a caller-owned byte buffer or a fixed-capacity array on the stack, without allocating one `String` per password.
+
+```rust
+use passwords::PasswordGenerator;
+
+let pgi = PasswordGenerator::new().length(10).try_iter().unwrap();
+
+let mut password = String::with_capacity(10);
+pgi.fill_string(&mut password);
+
+let mut buffer = [0u8; 16];
+let password = pgi.fill_bytes(&mut buffer).unwrap();
+
+let password = pgi.generate_array::<16>().unwrap();
+println!("{}", password);
+```
+
+With the **rayon** feature, the `par_generate` method of `PasswordGeneratorIter` can generate a large number of passwords across all cores, and the `par_generate_unique` method can generate distinct ones.
+
+```rust
+# #[cfg(feature = "rayon")]
+# {
+use passwords::PasswordGenerator;
+
+let pgi = PasswordGenerator::new().length(12).strict(true).try_iter().unwrap();
+
+assert_eq!(100000, pgi.par_generate(100000).len());
+assert_eq!(100000, pgi.par_generate_unique(100000).unwrap().len());
+# }
+```
+
+A length range, like "12–16 characters", can be set instead of a fixed length, so that passwords in a batch do not share the same length. The length of each password is picked uniformly by default, or in proportion to its count of passwords, which favors longer lengths and makes every password equally likely. The choice of the length is included in the entropy, and strict rules are checked against the minimum length.
+
+```rust
+use passwords::{LengthDistribution, PasswordGenerator};
+
+let pgi = PasswordGenerator::new().length_range(12..=16).uppercase_letters(true).length_distribution(LengthDistribution::Proportional).try_iter().unwrap();
+
+let password = pgi.generate_one();
+
+assert!((12..=16).contains(&password.len()));
+
+println!("{} bits", pgi.entropy());
+```
+
+Passwords which are read aloud or copied by hand can be generated in the transcription-safe mode, which excludes adjacent pairs of characters that handwriting and common fonts confuse, like `rn` for `m` and `vv` for `w`. The pairs can be replaced with your own table, and the excluded passwords are not counted by the `entropy` method.
+
+```rust
+use passwords::PasswordGenerator;
+
+let pg = PasswordGenerator::new().length(12).transcription_safe(true);
+
+let password = pg.generate_one().unwrap();
+
+assert!(!password.contains("rn"));
+
+let pairs = vec![['r', 'n'], ['u', 'v']];
+
+let pgi = pg.confusable_pairs(&pairs).try_iter().unwrap();
+
+println!("{} bits", pgi.entropy());
+```
+
+Passwords shown to people, like one-time codes, can exclude offensive words from an embedded multilingual blocklist. The words are matched case-insensitively and with common leet substitutions, like `4` for `a` and `0` for `o`. The excluded passwords are not counted by the `entropy` method, so the entropy loss is reported exactly. `TokenGenerator` and `SecretGenerator` have the same option.
+
+```rust
+use passwords::{PasswordGenerator, contains_offensive_word};
+
+let pgi = PasswordGenerator::new().length(6).uppercase_letters(true).exclude_offensive_words(true).try_iter().unwrap();
+
+assert!(contains_offensive_word("xxB1tchxx"));
+
+for password in pgi.generate(100) {
+    assert!(!contains_offensive_word(password));
+}
+
+println!("{} bits", pgi.entropy());
+```
+
+The `self_test` method samples passwords and runs statistical health tests on the picked characters: a chi-square test of uniformity, a chi-square test of positional bias, and the repetition count and adaptive proportion tests of NIST SP 800-90B. It can be called at startup to detect a misconfigured pool or a broken random source.
+
+```rust
+use passwords::PasswordGenerator;
+
+let pgi = PasswordGenerator::new().length(16).try_iter().unwrap();
+
+let report = pgi.self_test(10000).unwrap();
+
+println!("{:?}", report);
+
+assert!(report.passed());
+```
+
+With the **crypto** feature, passwords can also be derived statelessly from a master secret, a site, a login and a counter. The same inputs always yield the same password which satisfies the rules of the generator, and changing the counter rotates it.
+
+```rust
+# #[cfg(feature = "crypto")]
+# {
+use passwords::PasswordGenerator;
+
+let pg = PasswordGenerator::new().length(16).uppercase_letters(true).symbols(true).strict(true);
+
+let password = pg.derive("master secret", "example.org", "alice", 1).unwrap();
+
+assert_eq!(password, pg.derive("master secret", "example.org", "alice", 1).unwrap());
+assert_ne!(password, pg.derive("master secret", "example.org", "alice", 2).unwrap());
+# }
+```
+
+## Composite Password Generator
+
+`CompositePasswordGenerator` can be used for generating passwords composed of ordered segments, like "4 uppercase letters + 2 digits + 1 symbol". Each segment has its own `PasswordGenerator` settings. The characters of all segments can be shuffled uniformly at the end, and the entropy report takes the shuffling into account. If the characters of shuffled segments overlap, the passwords are not equally likely, so only a lower bound of the entropy is reported, by the `entropy_lower_bound` method. Segments cannot exclude offensive words or be transcription-safe, because such words and confusable pairs can still be formed across segments.
+
+```rust
+use passwords::{CompositePasswordGenerator, PasswordGenerator};
+
+let cpg = CompositePasswordGenerator::new()
+    .segment(PasswordGenerator::new().length(4).numbers(false).lowercase_letters(false).uppercase_letters(true))
+    .segment(PasswordGenerator::new().length(2).lowercase_letters(false))
+    .segment(PasswordGenerator::new().length(1).numbers(false).lowercase_letters(false).symbols(true))
+    .shuffle(true);
+
+println!("{}", cpg.generate_one().unwrap());
+println!("{:.2} bits", cpg.entropy().unwrap());
+```
+
+## Passphrase Generator
+
+`PassphraseGenerator` can be used for generating passphrases by picking words uniformly from a wordlist. The English list is built in, and localized lists can be enabled by the **wordlist-de**, **wordlist-fr**, **wordlist-es**, **wordlist-pt**, **wordlist-it**, **wordlist-cs** and **wordlist-ja-romaji** features. All words are normalized to lowercase ASCII without diacritics, and Japanese words are romanized with long vowels spelled as typed, like `arigatou`. The lists are the BIP39 wordlists of 2048 words, except the curated German list of 1454 words, so they are smaller than diceware lists of 7776 words, and the entropy is computed from the size of the chosen list. Pick more words to reach the same entropy, or use a diceware list of your own with `Wordlist::Custom`.
+
+```rust
+use passwords::{PassphraseGenerator, Wordlist};
+
+let pg = PassphraseGenerator::new().words(6).separator('-').wordlist(Wordlist::English);
+
+println!("{}", pg.generate_one().unwrap());
+println!("{:.2} bits", pg.entropy());
+```
+
+```toml
+[dependencies.passwords]
+version = "*"
+features = ["wordlist-de", "wordlist-pt"]
+```
+
+```rust
+use passwords::{PassphraseGenerator, Wordlist};
+
+static MY_WORDS: [&str; 4] = ["abacus", "abdomen", "abdominal", "abide"];
+
+let pg = PassphraseGenerator::new().words(8).wordlist(Wordlist::Custom(&MY_WORDS));
+
+assert_eq!(16.0, pg.entropy());
+```
+
+## Markov Password Generator
+
+`MarkovPasswordGenerator` can be used for generating human-looking but truly random passwords, like `bulter-jactin-hoil-buireparre`, by sampling pseudo-words from a character-level Markov model which is embedded in this crate. Pseudo-words are appended until the password reaches the minimum entropy, and the exact probability of each password can be computed.
+
+```rust
+use passwords::MarkovPasswordGenerator;
+
+let mg = MarkovPasswordGenerator::new().min_entropy(64.0);
+
+let password = mg.generate_one().unwrap();
+
+assert!(mg.log2_probability(&password).unwrap() <= -64.0);
+```
+
+## Token Generator
+
+`TokenGenerator` can be used for generating prefixed API tokens, like `ghp_` tokens. A token consists of `prefix_`, a random base62 body carrying at least the given bits of entropy, and a base62-encoded CRC32 checksum of the body, so secret-scanning tools can detect leaked tokens reliably and tokens can be validated offline.
+
+```rust
+use passwords::TokenGenerator;
+
+let tg = TokenGenerator::new().prefix("myapp").entropy_bits(128);
+
+let token = tg.generate_one().unwrap();
+
+assert!(token.starts_with("myapp_"));
+assert!(tg.validate_token(&token));
+```
+
+## Secret Generator
+
+`SecretGenerator` can be used for generating session secrets, CSRF keys, webhook secrets, etc. Every secret carries exactly the requested bits of entropy, encoded in hex, RFC 4648 base32, base64url without padding or base58. If offensive words are excluded, the secrets are lengthened, so that they still carry at least the requested bits.
+
+```rust
+use passwords::{SecretEncoding, SecretGenerator};
+
+let sg = SecretGenerator::new().bits(128).encoding(SecretEncoding::Hex);
+
+let secret = sg.generate_one().unwrap();
+
+assert_eq!(32, secret.len());
+```
+
+## Wi-Fi Passphrase Generator
+
+`WifiPassphraseGenerator` can be used for generating WPA2/WPA3 passphrases for guest Wi-Fi networks. By default, a passphrase consists of 16 numbers and lowercase letters without similar characters, which is easy to type on TVs. A `WifiPayload` can be formatted to the standard `WIFI:T:WPA;S:<ssid>;P:<passphrase>;;` payload for QR codes.
+
+```rust
+use passwords::{WifiPassphraseGenerator, WifiPayload};
+
+let payload = WifiPassphraseGenerator::new().generate_payload("Guest").unwrap();
+
+println!("{}", payload);
+
+assert_eq!(
+    r#"WIFI:T:WPA;S:Cafe\;Guest;P:p\:ss\,word;;"#,
+    WifiPayload::new("Cafe;Guest", "p:ss,word").unwrap().to_string()
+);
+```
+
+With the **qrcodegen** feature, the payload can be rendered as a QR code in text or SVG.
+
+```rust
+# #[cfg(feature = "qrcodegen")]
+# {
+use passwords::WifiPassphraseGenerator;
+
+let payload = WifiPassphraseGenerator::new().generate_payload("Guest").unwrap();
+
+println!("{}", payload.to_qr_text());
+println!("{}", payload.to_qr_svg());
+# }
+```
+
+## Grouped Password Generator
+
+`GroupedPasswordGenerator` can be used for generating long passwords which are displayed in groups, like `k7Qm-p2Xs-9vTn-Hb4w`, so they are easier to read. The separator is guaranteed not to be in the pool of the `PasswordGenerator`, which cannot contain spaces either, so `normalize_input` losslessly recovers the password from what a person types back in, by stripping separators and whitespace.
+
+```rust
+use passwords::GroupedPasswordGenerator;
+
+let gg = GroupedPasswordGenerator::new().group_size(4).separator('-');
+
+println!("{}", gg.generate_one().unwrap());
+
+assert_eq!("k7Qm-p2Xs-9vTn-Hb4w", gg.format("k7Qmp2Xs9vTnHb4w"));
+assert_eq!("k7Qmp2Xs9vTnHb4w", gg.normalize_input("k7Qm-p2Xs 9vTn - Hb4w"));
+```
+
+## PIN Generator
+
+`PinGenerator` can be used for generating PINs without weak ones, such as PINs with repeated digits, ascending or descending runs, year-like or date-like values, and frequently chosen PINs. It can also report the remaining keyspace, which helps you choose the length of PINs.
+
+```rust
+use passwords::PinGenerator;
+
+let pg = PinGenerator::new().length(4);
+
+assert!(pg.is_weak("1234"));
+assert!(pg.is_weak("1990"));
+
+println!("{}", pg.generate_one().unwrap());
+println!("{} PINs remain ({:.2} bits)", pg.keyspace(), pg.entropy());
+```
+
+## Mnemonic
+
+To enable mnemonics, you need to enable the **mnemonic** feature.
+
+```toml
+[dependencies.passwords]
+version = "*"
+features = ["mnemonic"]
+```
+
+Then, `MnemonicGenerator` can be used for generating word sequences which back up high-value secrets on paper, and the `encode`, `decode` and `suggest_corrections` functions in the `mnemonic` module are available. Mnemonics use the BIP39 English word list and a SHA-256 checksum, so they are compatible with BIP39, and a mistyped word is detected when a mnemonic is decoded.
+
+```rust
+# #[cfg(feature = "mnemonic")]
+# {
+use passwords::{MnemonicGenerator, mnemonic};
+
+let words = MnemonicGenerator::new().words(12).generate_one().unwrap();
+
+println!("{}", words);
+
+assert_eq!(
+    "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    mnemonic::encode([0x7f; 16]).unwrap()
+);
+
+assert_eq!(vec![0x7f; 16], mnemonic::decode("legal winner thank year wave sausage worth useful legal winner thank yellow").unwrap());
+
+let corrections = mnemonic::suggest_corrections("legal winner thank year wave sausage worth usefull legal winner thank yellow");
+
+assert_eq!((7, "useful"), (corrections[0].index, corrections[0].word));
+# }
+```
+
+## External Entropy
+
+`ExternalEntropy` can be used for generating passwords, passphrases and PINs on air-gapped machines from physical dice rolls or bytes supplied by you, instead of the CSPRNG. The input is mapped onto the pools by rejection sampling, so there is no bias. If the input runs out, nothing is consumed, and the error reports exactly how many more rolls or bytes are needed to finish if none of them is rejected, so you can roll more dice and try again.
+
+```rust
+use passwords::{ExternalEntropy, ExternalEntropyError, PassphraseGenerator, PasswordGenerator};
+
+let pg = PasswordGenerator::new();
+
+let mut entropy = ExternalEntropy::from_dice_rolls("3615 2436 15").unwrap();
+
+assert_eq!(Err(ExternalEntropyError::Exhausted { needed: 6 }), pg.generate_with_entropy(&mut entropy));
+
+entropy.extend_dice_rolls("5243 61").unwrap();
+
+println!("{}", pg.generate_with_entropy(&mut entropy).unwrap());
+
+let mut entropy = ExternalEntropy::from_bytes([0x3a, 0x91, 0x0c, 0xe7, 0x55, 0x02, 0xb8, 0x6f, 0x14, 0xd3, 0x7e, 0x29]);
+
+println!("{}", PassphraseGenerator::new().generate_with_entropy(&mut entropy).unwrap());
+```
+
+## Password Rotator
+
+`PasswordRotator` can be used for rotating passwords. It generates a new password whose edit distance to each previous password is large enough and whose longest common substring with each previous password is short enough, compared case-insensitively. The same check can validate a replacement chosen by a person.
+
+```rust
+use passwords::{PasswordGenerator, PasswordRotator};
+
+let pr = PasswordRotator::new().generator(PasswordGenerator::new().length(12)).min_edit_distance(6).max_common_substring(3);
+
+let previous = ["Summer2024!", "Winter2025!"];
+
+println!("{}", pr.generate(&previous).unwrap());
+
+assert!(pr.check(&previous, "SUMMER2026!").is_err());
+assert!(pr.is_different_enough(&previous, "tx7-Kq9-wLp"));
+```
+
+## Spoken Renderer
+
+`SpokenRenderer` can be used for reading passwords aloud, for example by helpdesk staff. Letters are rendered in the NATO phonetic alphabet with explicit case, and digits, symbols and spaces get clear names, in English or German. Other characters fall back to their Unicode names with the **unicode_names2** feature, or to their code points. The spoken form is available as plain text and as structured tokens.
+
+```rust
+use passwords::{SpokenLanguage, SpokenRenderer};
+
+let sr = SpokenRenderer::new();
+
+assert_eq!("capital Bravo, seven, at sign, lowercase X-ray", sr.render("B7@x"));
+
+let tokens = sr.language(SpokenLanguage::German).tokens("B7@x");
+
+assert_eq!("großes Bravo", tokens[0].text);
+assert_eq!("At-Zeichen", tokens[2].text);
+```
+
+**Without the unicode_names2 feature, every non-ASCII character, like `é`, is spoken only by its code point, like `U+00E9`, which is hard to read aloud.** Enable the feature if passwords may contain non-ASCII characters.
+
+```toml
+[dependencies.passwords]
+version = "*"
+features = ["unicode_names2"]
+```
+
+## Weak Password Synthesizer
+
+`WeakPasswordSynthesizer` can be used for synthesizing realistic weak passwords to test password policies and the thresholds of the `scorer` module. It needs the **common-password** feature. Passwords are synthesized from the table of common passwords with mutation rules, like appending years, leet substitutions, keyboard walks and `Password1!`-style decorations, and each one is labeled with the rule which produced it.
+
+```rust
+# #[cfg(feature = "common-password")]
+# {
+use passwords::{WeakPasswordRule, WeakPasswordSynthesizer};
+
+let ws = WeakPasswordSynthesizer::new().rules(&[WeakPasswordRule::AppendYear, WeakPasswordRule::Leet]);
+
+for weak in ws.generate(5).unwrap() {
+    println!("{}: {}", weak.rule, weak.password);
+}
+# }
+```
+
+## Password Rules
+
+`PasswordRules` can parse password constraints in the `passwordrules` attribute syntax, which extends the character classes of `PasswordGenerator` with custom character sets and a limit of consecutive identical characters. Every generated password satisfies the parsed rules.
+
+```rust
+use passwords::PasswordRules;
+
+let rules: PasswordRules = "minlength: 12; maxlength: 20; required: upper; required: digit; allowed: lower, [-_.]; max-consecutive: 2".parse().unwrap();
+
+let password = rules.generate_one().unwrap();
+
+assert_eq!(16, password.len());
+assert!(rules.is_satisfied_by(&password));
+
+let error = "minlength: 12; maxlength: twenty".parse::<PasswordRules>().unwrap_err();
+
+assert_eq!(26..32, error.span());
+```
+
+When one password must be accepted by several systems, the `intersection` function can combine their rules, which may also be converted from `PasswordGenerator`s. If the rules cannot be satisfied at the same time, the error names the conflicting pair.
+
+```rust
+use passwords::{PasswordGenerator, PasswordRules};
+
+let ldap: PasswordRules = "minlength: 10; required: upper; required: digit; allowed: lower".parse().unwrap();
+let mainframe: PasswordRules = "maxlength: 12; allowed: upper, lower, digit".parse().unwrap();
+let saas = PasswordRules::try_from(PasswordGenerator::new().length(12).uppercase_letters(true)).unwrap();
+
+let rules = PasswordRules::intersection(&[ldap, mainframe, saas]).unwrap();
+
+println!("{}", rules.generate_one().unwrap());
+
+let short: PasswordRules = "maxlength: 8".parse().unwrap();
+
+let conflict = PasswordRules::intersection(&[rules, short]).unwrap_err();
+
+assert_eq!((0, 1), (conflict.first(), conflict.second()));
+```
+
 ## Hasher
 
 To enable hashing functions, you need to enable the **crypto** feature.
@@ -65,7 +506,7 @@ version = "*"
 features = ["crypto"]
 ```
 
-Then, `bcrypt`, `identify_bcrypt`, `bcrypt_format`, `identify_bcrypt_format`, `get_password_with_null_terminated_byte` and `gen_salt` functions in the `hasher` module are available.
+Then, `bcrypt`, `identify_bcrypt`, `bcrypt_format`, `identify_bcrypt_format`, `get_password_with_null_terminated_byte` and `gen_salt` functions in the `hasher` module, and the `derive` and `generate_hashed` methods of `PasswordGenerator` are available.
 
 ```rust
 # #[cfg(feature = "crypto")]
@@ -82,6 +523,19 @@ assert!(unsafe { hasher::identify_bcrypt_format("password\0", mcf) });
 # }
 ```
 
+An initial password can be generated and hashed by bcrypt in one call. The plaintext is for one-time display, and the hash is in Modular Crypt Format.
+
+```rust
+# #[cfg(feature = "crypto")]
+# {
+use passwords::{PasswordGenerator, hasher};
+
+let (password, hashed) = PasswordGenerator::new().length(16).generate_hashed(10).unwrap();
+
+assert!(unsafe { hasher::identify_bcrypt_format(&format!("{password}\0"), hashed) });
+# }
+```
+
 ## Analyzer
 
 The `analyze` function in the `analyzer` module can be used to create a `AnalyzedPassword` instance which contains some information about the input password.
@@ -157,12 +611,49 @@ A password whose score is,
 
 /// Analyze passwords.
 pub mod analyzer;
+mod composite;
+mod entropy;
 mod generator;
+mod grouped;
 #[cfg(feature = "crypto")]
 /// Hash passwords.
 pub mod hasher;
+mod markov;
+#[cfg(feature = "mnemonic")]
+/// Encode and decode mnemonic secrets.
+pub mod mnemonic;
+mod offensive;
+mod passphrase;
+mod pin;
+mod rotation;
+mod rules;
 /// Score passwords.
 pub mod scorer;
+mod secret;
+mod spoken;
+mod token;
+mod weak;
+mod wifi;
 
 pub use analyzer::AnalyzedPassword;
-pub use generator::PasswordGenerator;
+pub use composite::CompositePasswordGenerator;
+pub use entropy::{ExternalEntropy, ExternalEntropyError};
+pub use generator::{
+    ChiSquareTest, CutoffTest, LengthDistribution, ParsePasswordGeneratorError, PasswordArray,
+    PasswordGenerator, PasswordGeneratorIter, SelfTestReport,
+};
+pub use grouped::GroupedPasswordGenerator;
+pub use markov::MarkovPasswordGenerator;
+#[cfg(feature = "mnemonic")]
+pub use mnemonic::MnemonicGenerator;
+pub use offensive::contains_offensive_word;
+pub use passphrase::{PassphraseGenerator, Wordlist};
+pub use pin::PinGenerator;
+pub use rotation::PasswordRotator;
+pub use rules::{PasswordRules, PasswordRulesConflict, PasswordRulesError};
+pub use secret::{SecretEncoding, SecretGenerator};
+pub use spoken::{SpokenLanguage, SpokenRenderer, SpokenToken, SpokenTokenKind};
+pub use token::TokenGenerator;
+#[cfg(feature = "common-password")]
+pub use weak::{SynthesizedPassword, WeakPasswordRule, WeakPasswordSynthesizer};
+pub use wifi::{WifiPassphraseGenerator, WifiPayload};
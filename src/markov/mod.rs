@@ -0,0 +1,208 @@
+use rand::{Rng, RngExt};
+
+/// The symbols of the model are `a` to `z` and `BOUNDARY`, which is the start or the end of a word.
+const SYMBOLS_COUNT: usize = 27;
+const BOUNDARY: usize = 26;
+
+/// The counts of an order-2 character-level Markov model, trained offline on the BIP39 English wordlist. The row `a * 27 + b` holds the counts of the symbols following the symbols `a` and `b`.
+static MODEL: [[u32; SYMBOLS_COUNT]; SYMBOLS_COUNT * SYMBOLS_COUNT] =
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/markov-model.json"));
+
+/// The information carried by a transition, in bits, which is `-log2` of its probability. `None` if the transition never happens.
+#[inline]
+fn transition_bits(context: usize, symbol: usize) -> Option<f64> {
+    let row = &MODEL[context];
+    let count = row[symbol];
+
+    if count == 0 {
+        return None;
+    }
+
+    let total: u32 = row.iter().sum();
+
+    Some((total as f64).log2() - (count as f64).log2())
+}
+
+#[inline]
+fn sample_symbol<R: Rng + ?Sized>(rng: &mut R, context: usize) -> usize {
+    let row = &MODEL[context];
+    let total: u32 = row.iter().sum();
+
+    let mut r = rng.random_range(..total);
+
+    for (symbol, &count) in row.iter().enumerate() {
+        if r < count {
+            return symbol;
+        }
+
+        r -= count;
+    }
+
+    unreachable!()
+}
+
+#[inline]
+const fn next_context(context: usize, symbol: usize) -> usize {
+    (context % SYMBOLS_COUNT) * SYMBOLS_COUNT + symbol
+}
+
+const START: usize = BOUNDARY * SYMBOLS_COUNT + BOUNDARY;
+
+/// This struct can help you generate human-looking but truly random passwords, like `bulter-jactin-hoil-buireparre`, by sampling pseudo-words from a character-level Markov model embedded in this crate.
+///
+/// Pseudo-words are appended, separated by `separator`, until the information carried by the password reaches `min_entropy`. Because the words are separated, each password is produced by only one path of the model, so its exact probability can be computed by the `log2_probability` method, and no password more likely than `2^-min_entropy` is ever generated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkovPasswordGenerator {
+    /// The minimum entropy of each generated password, in bits.
+    ///
+    /// Default: `64.0`
+    pub min_entropy: f64,
+    /// The separator between pseudo-words. It cannot be a lowercase ASCII letter.
+    ///
+    /// Default: `'-'`
+    pub separator:   char,
+}
+
+impl MarkovPasswordGenerator {
+    /// Create a `MarkovPasswordGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// MarkovPasswordGenerator {
+    ///     min_entropy: 64.0,
+    ///     separator: '-',
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> MarkovPasswordGenerator {
+        MarkovPasswordGenerator {
+            min_entropy: 64.0, separator: '-'
+        }
+    }
+
+    /// The minimum entropy of each generated password, in bits.
+    #[inline]
+    pub const fn min_entropy(mut self, min_entropy: f64) -> MarkovPasswordGenerator {
+        self.min_entropy = min_entropy;
+
+        self
+    }
+
+    /// The separator between pseudo-words.
+    #[inline]
+    pub const fn separator(mut self, separator: char) -> MarkovPasswordGenerator {
+        self.separator = separator;
+
+        self
+    }
+}
+
+impl MarkovPasswordGenerator {
+    fn check(&self) -> Result<(), &'static str> {
+        if !self.min_entropy.is_finite() || self.min_entropy < 0.0 {
+            return Err("The minimum entropy must be a non-negative finite number.");
+        }
+
+        if self.separator.is_ascii_lowercase() {
+            return Err("The separator cannot be a lowercase ASCII letter.");
+        }
+
+        Ok(())
+    }
+
+    /// The exact base-2 logarithm of the probability that this generator produces the input password, i.e. the negative of the entropy of the password, in bits. `None` if it can never be produced.
+    pub fn log2_probability<S: AsRef<str>>(&self, password: S) -> Option<f64> {
+        self.check().ok()?;
+
+        let mut bits = 0f64;
+        let mut words = password.as_ref().split(self.separator).peekable();
+
+        while let Some(word) = words.next() {
+            if word.is_empty() {
+                return None;
+            }
+
+            let mut context = START;
+
+            for b in word.bytes() {
+                if !b.is_ascii_lowercase() {
+                    return None;
+                }
+
+                let symbol = (b - b'a') as usize;
+
+                bits += transition_bits(context, symbol)?;
+                context = next_context(context, symbol);
+            }
+
+            bits += transition_bits(context, BOUNDARY)?;
+
+            // the generation stops as soon as the minimum entropy is reached
+            if (bits >= self.min_entropy) == words.peek().is_some() {
+                return None;
+            }
+        }
+
+        Some(-bits)
+    }
+
+    fn fill_password<R: Rng + ?Sized>(&self, rng: &mut R, password: &mut String) {
+        password.clear();
+
+        let mut bits = 0f64;
+
+        loop {
+            let mut context = START;
+
+            loop {
+                let symbol = sample_symbol(rng, context);
+
+                // the same summation as `log2_probability`, so that the results are identical
+                bits += transition_bits(context, symbol).unwrap();
+
+                if symbol == BOUNDARY {
+                    break;
+                }
+
+                password.push((b'a' + symbol as u8) as char);
+                context = next_context(context, symbol);
+            }
+
+            if bits >= self.min_entropy {
+                break;
+            }
+
+            password.push(self.separator);
+        }
+    }
+
+    /// Generate random passwords.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        self.check()?;
+
+        let mut rng = rand::rng();
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let mut password = String::new();
+
+            self.fill_password(&mut rng, &mut password);
+
+            result.push(password);
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random password.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+}
+
+impl Default for MarkovPasswordGenerator {
+    #[inline]
+    fn default() -> MarkovPasswordGenerator {
+        MarkovPasswordGenerator::new()
+    }
+}
@@ -0,0 +1,295 @@
+#![cfg(feature = "mnemonic")]
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{passphrase::ENGLISH, rotation::edit_distance};
+
+/// The counts of words which a mnemonic can have.
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// The maximum edit distance between a mistyped word and its suggested corrections.
+const MAX_CORRECTION_DISTANCE: usize = 2;
+
+/// An error which can be returned when decoding a mnemonic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MnemonicError {
+    /// The count of words is not 12, 15, 18, 21 or 24.
+    InvalidWordCount(usize),
+    /// The word at `index` is not in the word list.
+    UnknownWord { index: usize, word: String },
+    /// All words are in the word list, but the checksum does not match, so at least one of them is wrong.
+    InvalidChecksum,
+}
+
+impl Display for MnemonicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MnemonicError::InvalidWordCount(count) => {
+                write!(f, "a mnemonic must have 12, 15, 18, 21 or 24 words, not {count}")
+            },
+            MnemonicError::UnknownWord {
+                index,
+                word,
+            } => write!(f, "the word {word:?} at index {index} is not in the word list"),
+            MnemonicError::InvalidChecksum => {
+                f.write_str("the checksum of the mnemonic is invalid")
+            },
+        }
+    }
+}
+
+impl Error for MnemonicError {}
+
+/// A suggested correction of a single word of a mnemonic, with which the checksum matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicCorrection {
+    /// The index of the word to replace.
+    pub index:    usize,
+    /// The word as it was typed.
+    pub typed:    String,
+    /// The replacement.
+    pub word:     &'static str,
+    /// The edit distance between the typed word and the replacement.
+    pub distance: usize,
+}
+
+/// The checksum of the entropy, which is the first `entropy.len() / 4` bits of its SHA-256 digest, at the high end of a byte.
+#[inline]
+fn checksum(entropy: &[u8]) -> u8 {
+    let bits = entropy.len() / 4;
+
+    Sha256::digest(entropy)[0] & (0xFFu8 << (8 - bits))
+}
+
+/// Recover the entropy from the indices of the words, if the checksum matches.
+fn entropy_of_indices(indices: &[usize]) -> Option<Vec<u8>> {
+    let mut bytes = vec![0u8; (indices.len() * 11).div_ceil(8)];
+
+    for (i, &index) in indices.iter().enumerate() {
+        for bit in 0..11 {
+            if index & (1 << (10 - bit)) != 0 {
+                let position = i * 11 + bit;
+
+                bytes[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+    }
+
+    // the entropy has 32 bits for each 3 words, followed by 1 bit of the checksum for each 3 words
+    let entropy_length = indices.len() / 3 * 4;
+    let checksum_byte = bytes[entropy_length];
+
+    bytes.truncate(entropy_length);
+
+    if checksum(&bytes) == checksum_byte { Some(bytes) } else { None }
+}
+
+/// Split a mnemonic into lowercase words.
+#[inline]
+fn split_words(mnemonic: &str) -> Vec<String> {
+    mnemonic.split_whitespace().map(str::to_lowercase).collect()
+}
+
+#[inline]
+fn index_of_word(word: &str) -> Option<usize> {
+    ENGLISH.binary_search(&word).ok()
+}
+
+/// Encode 16, 20, 24, 28 or 32 bytes of entropy into a mnemonic of 12, 15, 18, 21 or 24 words respectively, with the BIP39 English word list.
+pub fn encode<B: AsRef<[u8]>>(entropy: B) -> Result<String, &'static str> {
+    let entropy = entropy.as_ref();
+
+    if !(16..=32).contains(&entropy.len()) || entropy.len() % 4 != 0 {
+        return Err("The entropy of a mnemonic must be 16, 20, 24, 28 or 32 bytes.");
+    }
+
+    let mut bytes = entropy.to_vec();
+
+    bytes.push(checksum(entropy));
+
+    let word_count = entropy.len() * 3 / 4;
+
+    let mut mnemonic = String::new();
+
+    for i in 0..word_count {
+        let index = (0..11).fold(0usize, |index, bit| {
+            let position = i * 11 + bit;
+
+            (index << 1) | usize::from(bytes[position / 8] & (0x80 >> (position % 8)) != 0)
+        });
+
+        if i > 0 {
+            mnemonic.push(' ');
+        }
+
+        mnemonic.push_str(ENGLISH[index]);
+    }
+
+    Ok(mnemonic)
+}
+
+/// Decode a mnemonic into its entropy. Words are separated by whitespace and case-insensitive.
+pub fn decode<S: AsRef<str>>(mnemonic: S) -> Result<Vec<u8>, MnemonicError> {
+    let words = split_words(mnemonic.as_ref());
+
+    if !WORD_COUNTS.contains(&words.len()) {
+        return Err(MnemonicError::InvalidWordCount(words.len()));
+    }
+
+    let mut indices = Vec::with_capacity(words.len());
+
+    for (index, word) in words.into_iter().enumerate() {
+        match index_of_word(&word) {
+            Some(i) => indices.push(i),
+            None => {
+                return Err(MnemonicError::UnknownWord {
+                    index,
+                    word,
+                });
+            },
+        }
+    }
+
+    entropy_of_indices(&indices).ok_or(MnemonicError::InvalidChecksum)
+}
+
+/// Whether a mnemonic can be decoded.
+#[inline]
+pub fn is_valid<S: AsRef<str>>(mnemonic: S) -> bool {
+    decode(mnemonic).is_ok()
+}
+
+/// Suggest corrections for a mnemonic with a single mistyped word, sorted by the edit distance to the typed word. Only replacements within an edit distance of 2 with which the checksum matches are suggested.
+///
+/// If a word is not in the word list, only that word is replaced. If all words are in the word list but the checksum does not match, every word is tried. Nothing is suggested for a valid mnemonic, a mnemonic with an invalid count of words, or a mnemonic with more than one unknown word.
+pub fn suggest_corrections<S: AsRef<str>>(mnemonic: S) -> Vec<MnemonicCorrection> {
+    let words = split_words(mnemonic.as_ref());
+
+    if !WORD_COUNTS.contains(&words.len()) {
+        return Vec::new();
+    }
+
+    let indices: Vec<Option<usize>> = words.iter().map(|word| index_of_word(word)).collect();
+
+    let unknown: Vec<usize> = (0..words.len()).filter(|&i| indices[i].is_none()).collect();
+
+    let mut indices: Vec<usize> = indices.into_iter().map(|index| index.unwrap_or(0)).collect();
+
+    let positions = match unknown.len() {
+        0 if entropy_of_indices(&indices).is_some() => return Vec::new(),
+        0 => (0..words.len()).collect(),
+        1 => unknown,
+        _ => return Vec::new(),
+    };
+
+    let mut corrections = Vec::new();
+
+    for position in positions {
+        let typed: Vec<char> = words[position].chars().collect();
+        let original = indices[position];
+
+        for (index, word) in ENGLISH.iter().enumerate() {
+            let distance = edit_distance(&typed, &word.chars().collect::<Vec<char>>());
+
+            if distance == 0 || distance > MAX_CORRECTION_DISTANCE {
+                continue;
+            }
+
+            indices[position] = index;
+
+            if entropy_of_indices(&indices).is_some() {
+                corrections.push(MnemonicCorrection {
+                    index: position,
+                    typed: words[position].clone(),
+                    word,
+                    distance,
+                });
+            }
+        }
+
+        indices[position] = original;
+    }
+
+    corrections.sort_by_key(|correction| correction.distance);
+
+    corrections
+}
+
+/// This struct can help you generate random mnemonics, which are written down on paper to back up high-value secrets.
+///
+/// A mnemonic of `words` words carries `words * 32 / 3` bits of entropy, encoded with the BIP39 English word list and a SHA-256 checksum, so a mistyped word is detected when the mnemonic is decoded and can be corrected by `suggest_corrections`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicGenerator {
+    /// The count of words of the generated mnemonics, 12, 15, 18, 21 or 24.
+    ///
+    /// Default: `24`
+    pub words: usize,
+}
+
+impl MnemonicGenerator {
+    /// Create a `MnemonicGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// MnemonicGenerator {
+    ///     words: 24,
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> MnemonicGenerator {
+        MnemonicGenerator {
+            words: 24
+        }
+    }
+
+    /// The count of words of the generated mnemonics, 12, 15, 18, 21 or 24.
+    #[inline]
+    pub const fn words(mut self, words: usize) -> MnemonicGenerator {
+        self.words = words;
+
+        self
+    }
+}
+
+impl MnemonicGenerator {
+    /// The entropy of the generated mnemonics, in bits.
+    #[inline]
+    pub const fn entropy(&self) -> usize {
+        self.words * 32 / 3
+    }
+
+    /// Generate random mnemonics.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        if !WORD_COUNTS.contains(&self.words) {
+            return Err("The count of words of a mnemonic must be 12, 15, 18, 21 or 24.");
+        }
+
+        let mut entropy = vec![0u8; self.entropy() / 8];
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            rand::fill(entropy.as_mut_slice());
+
+            result.push(encode(&entropy)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random mnemonic.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+}
+
+impl Default for MnemonicGenerator {
+    #[inline]
+    fn default() -> MnemonicGenerator {
+        MnemonicGenerator::new()
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::OnceLock,
+};
+
+static OFFENSIVE_WORDS: &[&str] =
+    &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/offensive-words.json"));
+
+/// The symbols of the automaton are the 26 letters and `OTHER`, which is any other character.
+const SYMBOLS_COUNT: usize = 27;
+const OTHER: u8 = 26;
+
+/// Map a character to a letter, case-insensitively and with common leet substitutions, like `4` and `@` for `a`. Each character is mapped to at most one letter, so that `1` is always read as `i`.
+#[inline]
+fn symbol(c: char) -> u8 {
+    let letter = match c {
+        'a'..='z' => c as u8,
+        'A'..='Z' => c.to_ascii_lowercase() as u8,
+        '4' | '@' => b'a',
+        '8' => b'b',
+        '(' => b'c',
+        '3' => b'e',
+        '6' | '9' => b'g',
+        '1' | '!' => b'i',
+        '|' => b'l',
+        '0' => b'o',
+        '5' | '$' => b's',
+        '7' | '+' => b't',
+        '2' => b'z',
+        _ => return OTHER,
+    };
+
+    letter - b'a'
+}
+
+/// An Aho-Corasick automaton of the offensive words, whose transitions are completed for every symbol.
+struct Automaton {
+    transitions: Vec<[usize; SYMBOLS_COUNT]>,
+    terminal:    Vec<bool>,
+}
+
+impl Automaton {
+    fn build() -> Automaton {
+        let mut transitions = vec![[usize::MAX; SYMBOLS_COUNT]];
+        let mut terminal = vec![false];
+
+        for word in OFFENSIVE_WORDS {
+            let mut state = 0;
+
+            for b in word.bytes() {
+                let s = (b - b'a') as usize;
+
+                if transitions[state][s] == usize::MAX {
+                    transitions.push([usize::MAX; SYMBOLS_COUNT]);
+                    terminal.push(false);
+
+                    transitions[state][s] = transitions.len() - 1;
+                }
+
+                state = transitions[state][s];
+            }
+
+            terminal[state] = true;
+        }
+
+        // breadth-first, so the failure state of a state is always completed before it
+        let mut failure = vec![0; transitions.len()];
+        let mut queue = std::collections::VecDeque::new();
+
+        for next in transitions[0].iter_mut() {
+            match *next {
+                usize::MAX => *next = 0,
+                _ => queue.push_back(*next),
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            terminal[state] |= terminal[failure[state]];
+
+            let fallbacks = transitions[failure[state]];
+
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                match *next {
+                    usize::MAX => *next = fallback,
+                    _ => {
+                        failure[*next] = fallback;
+                        queue.push_back(*next);
+                    },
+                }
+            }
+        }
+
+        Automaton {
+            transitions,
+            terminal,
+        }
+    }
+
+    #[inline]
+    fn get() -> &'static Automaton {
+        static AUTOMATON: OnceLock<Automaton> = OnceLock::new();
+
+        AUTOMATON.get_or_init(Automaton::build)
+    }
+
+    /// The next state, or `None` if an offensive word ends here.
+    #[inline]
+    fn next(&self, state: usize, symbol: u8) -> Option<usize> {
+        let next = self.transitions[state][symbol as usize];
+
+        if self.terminal[next] { None } else { Some(next) }
+    }
+}
+
+/// Whether the input string contains an offensive word from the embedded multilingual blocklist.
+///
+/// Letters are compared case-insensitively, and common leet substitutions are recognized: `4 @` for `a`, `8` for `b`, `(` for `c`, `3` for `e`, `6 9` for `g`, `1 !` for `i`, `|` for `l`, `0` for `o`, `5 $` for `s`, `7 +` for `t` and `2` for `z`. Any other character breaks a word.
+pub fn contains_offensive_word<S: AsRef<str>>(s: S) -> bool {
+    let automaton = Automaton::get();
+
+    let mut state = 0;
+
+    for c in s.as_ref().chars() {
+        match automaton.next(state, symbol(c)) {
+            Some(next) => state = next,
+            None => return true,
+        }
+    }
+
+    false
+}
+
+/// Compute the base-2 logarithm of the count of strings of `length` characters which do not contain offensive words, by dynamic programming over the states of the automaton and an extra state, like the kinds of characters seen so far.
+///
+/// `choices(position, extra)` lists the characters allowed at a position with their next extra states, which are smaller than `extras`. Only the strings whose final extra state is accepted by `accept` are counted. The result is negative infinity if no string is counted.
+pub(crate) fn log2_count_clean<
+    C: FnMut(usize, usize) -> Vec<(char, usize)>,
+    A: Fn(usize) -> bool,
+>(
+    length: usize,
+    extras: usize,
+    initial_extra: usize,
+    mut choices: C,
+    accept: A,
+) -> f64 {
+    let automaton = Automaton::get();
+    let states = automaton.transitions.len();
+
+    let mut counts = vec![0f64; states * extras];
+    let mut log2_scale = 0f64;
+
+    counts[initial_extra] = 1.0;
+
+    for position in 0..length {
+        // characters mapped to the same symbol behave the same in the automaton
+        let grouped: Vec<Vec<(u8, usize, f64)>> = (0..extras)
+            .map(|extra| {
+                let mut grouped: Vec<(u8, usize, f64)> = Vec::new();
+
+                for (c, next_extra) in choices(position, extra) {
+                    let symbol = symbol(c);
+
+                    match grouped.iter_mut().find(|(s, e, _)| *s == symbol && *e == next_extra) {
+                        Some((_, _, weight)) => *weight += 1.0,
+                        None => grouped.push((symbol, next_extra, 1.0)),
+                    }
+                }
+
+                grouped
+            })
+            .collect();
+
+        let mut next_counts = vec![0f64; states * extras];
+
+        for state in 0..states {
+            for (extra, grouped) in grouped.iter().enumerate() {
+                let count = counts[state * extras + extra];
+
+                if count == 0.0 {
+                    continue;
+                }
+
+                for &(symbol, next_extra, weight) in grouped {
+                    if let Some(next) = automaton.next(state, symbol) {
+                        next_counts[next * extras + next_extra] += count * weight;
+                    }
+                }
+            }
+        }
+
+        // rescale to keep the counts in the range of `f64`
+        let sum: f64 = next_counts.iter().sum();
+
+        if sum == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+
+        for count in next_counts.iter_mut() {
+            *count /= sum;
+        }
+
+        log2_scale += sum.log2();
+        counts = next_counts;
+    }
+
+    let accepted: f64 = counts
+        .chunks(extras)
+        .flat_map(|chunk| chunk.iter().enumerate())
+        .filter(|&(extra, _)| accept(extra))
+        .map(|(_, count)| count)
+        .sum();
+
+    log2_scale + accepted.log2()
+}
+
+/// The tolerance under which two successive normalized rows of a table for picking characters are considered the same, so that the following rows are not stored.
+pub(crate) const CONVERGENCE_TOLERANCE: f64 = 1e-15;
+
+/// A table of the counts of the ways to complete a string, for picking its characters one by one so that every valid string is equally likely, without generating whole strings and rejecting them.
+///
+/// The strings are the same as the ones counted by `log2_count_clean`, with every final extra state accepted. If `exclude_offensive_words` is false, the automaton is not used, so only the extra states constrain the strings.
+#[derive(Clone, PartialEq)]
+pub(crate) struct CleanSampler {
+    exclude_offensive_words: bool,
+    extras:                  usize,
+    initial_extra:           usize,
+    /// `choices[position][extra]`, or only `choices[0]` if the choices do not depend on the position.
+    choices:                 Vec<Vec<Vec<(char, usize)>>>,
+    /// `rows[k][state * extras + extra]` is proportional to the count of the ways to complete a string with `k` more characters from an automaton state and an extra state. The rows converge if the choices do not depend on the position, so the last row is reused for bigger `k`.
+    rows:                    Vec<Vec<f64>>,
+}
+
+impl Debug for CleanSampler {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CleanSampler")
+            .field("exclude_offensive_words", &self.exclude_offensive_words)
+            .field("extras", &self.extras)
+            .field("rows", &self.rows.len())
+            .finish()
+    }
+}
+
+impl CleanSampler {
+    /// Build the table for strings of up to `length` characters. `choices(position, extra)` lists the characters allowed at a position with their next extra states, which are smaller than `extras`. If `positional` is false, the choices do not depend on the position, and the table can be used for any length up to `length`. Otherwise, it can only be used for exactly `length` characters.
+    pub(crate) fn new<C: FnMut(usize, usize) -> Vec<(char, usize)>>(
+        exclude_offensive_words: bool,
+        length: usize,
+        extras: usize,
+        initial_extra: usize,
+        positional: bool,
+        mut choices: C,
+    ) -> CleanSampler {
+        let positions = if positional { length } else { 1 };
+
+        let choices: Vec<Vec<Vec<(char, usize)>>> = (0..positions)
+            .map(|position| (0..extras).map(|extra| choices(position, extra)).collect())
+            .collect();
+
+        let mut sampler = CleanSampler {
+            exclude_offensive_words,
+            extras,
+            initial_extra,
+            choices,
+            rows: Vec::new(),
+        };
+
+        let states = sampler.states();
+
+        sampler.rows.push(vec![1.0; states * extras]);
+
+        for k in 1..length {
+            let position = if positional { length - k } else { 0 };
+            let previous = &sampler.rows[k - 1];
+
+            let mut row = vec![0f64; states * extras];
+
+            for state in 0..states {
+                for extra in 0..extras {
+                    row[state * extras + extra] = sampler.choices[position][extra]
+                        .iter()
+                        .map(|&(c, next_extra)| sampler.weight(previous, state, c, next_extra))
+                        .sum();
+                }
+            }
+
+            // normalize to keep the counts in the range of `f64`
+            let sum: f64 = row.iter().sum();
+
+            if sum > 0.0 {
+                for count in row.iter_mut() {
+                    *count /= sum;
+                }
+            }
+
+            let converged = !positional
+                && row.iter().zip(previous).all(|(a, b)| (a - b).abs() <= CONVERGENCE_TOLERANCE);
+
+            if converged {
+                break;
+            }
+
+            sampler.rows.push(row);
+        }
+
+        sampler
+    }
+
+    #[inline]
+    fn states(&self) -> usize {
+        if self.exclude_offensive_words { Automaton::get().transitions.len() } else { 1 }
+    }
+
+    /// The next automaton state, or `None` if an offensive word ends here.
+    #[inline]
+    fn next_state(&self, state: usize, c: char) -> Option<usize> {
+        if self.exclude_offensive_words { Automaton::get().next(state, symbol(c)) } else { Some(0) }
+    }
+
+    #[inline]
+    fn weight(&self, row: &[f64], state: usize, c: char, next_extra: usize) -> f64 {
+        self.next_state(state, c).map_or(0.0, |next| row[next * self.extras + next_extra])
+    }
+
+    /// Pick a string of `length` characters. `random(position)` returns a uniformly random number in `[0, 1)` for the character at a position, and `push(position, c)` receives the picked character.
+    pub(crate) fn sample<E, R: FnMut(usize) -> Result<f64, E>, P: FnMut(usize, char)>(
+        &self,
+        length: usize,
+        mut random: R,
+        mut push: P,
+    ) -> Result<(), E> {
+        let mut state = 0;
+        let mut extra = self.initial_extra;
+
+        for position in 0..length {
+            let row = &self.rows[(length - position - 1).min(self.rows.len() - 1)];
+            let choices = &self.choices[position.min(self.choices.len() - 1)][extra];
+
+            let total: f64 =
+                choices.iter().map(|&(c, next_extra)| self.weight(row, state, c, next_extra)).sum();
+
+            debug_assert!(total > 0.0);
+
+            let mut r = random(position)? * total;
+            let mut picked = None;
+
+            for &(c, next_extra) in choices {
+                let weight = self.weight(row, state, c, next_extra);
+
+                if weight == 0.0 {
+                    continue;
+                }
+
+                picked = Some((c, next_extra));
+
+                if r < weight {
+                    break;
+                }
+
+                r -= weight;
+            }
+
+            // the last possible character is picked if `r` is not consumed because of rounding
+            let (c, next_extra) = picked.unwrap();
+
+            if let Some(next) = self.next_state(state, c) {
+                state = next;
+            }
+
+            extra = next_extra;
+
+            push(position, c);
+        }
+
+        Ok(())
+    }
+}
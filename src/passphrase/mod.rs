@@ -0,0 +1,246 @@
+use rand::RngExt;
+
+use crate::{ExternalEntropy, ExternalEntropyError};
+
+pub(crate) static ENGLISH: &[&str] =
+    &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/en.json"));
+
+#[cfg(feature = "wordlist-de")]
+static GERMAN: &[&str] = &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/de.json"));
+
+#[cfg(feature = "wordlist-fr")]
+static FRENCH: &[&str] = &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/fr.json"));
+
+#[cfg(feature = "wordlist-es")]
+static SPANISH: &[&str] = &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/es.json"));
+
+#[cfg(feature = "wordlist-pt")]
+static PORTUGUESE: &[&str] =
+    &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/pt.json"));
+
+#[cfg(feature = "wordlist-it")]
+static ITALIAN: &[&str] = &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/it.json"));
+
+#[cfg(feature = "wordlist-cs")]
+static CZECH: &[&str] = &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/cs.json"));
+
+#[cfg(feature = "wordlist-ja-romaji")]
+static JAPANESE_ROMAJI: &[&str] =
+    &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/wordlists/ja-romaji.json"));
+
+/// The wordlists for passphrases. Each list except English is enabled by its own `wordlist-*` feature.
+///
+/// All words are normalized in the same way: they are lowercased, diacritics are removed (`é` becomes `e` and `ü` becomes `u`), `ß`, `æ` and `œ` become `ss`, `ae` and `oe`, and kana is romanized with Hepburn consonants but with long vowels spelled as they are typed with a kana input method, like `ou` in `arigatou`, since the macrons of Hepburn would be removed anyway. Words which become the same after the normalization are merged, so every word in a list is unique ASCII and can be typed on any keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Wordlist {
+    /// The BIP39 English wordlist, 2048 words.
+    #[default]
+    English,
+    /// A curated German wordlist, 1454 words. It needs the **wordlist-de** feature.
+    #[cfg(feature = "wordlist-de")]
+    German,
+    /// The BIP39 French wordlist, 2048 words. It needs the **wordlist-fr** feature.
+    #[cfg(feature = "wordlist-fr")]
+    French,
+    /// The BIP39 Spanish wordlist, 2048 words. It needs the **wordlist-es** feature.
+    #[cfg(feature = "wordlist-es")]
+    Spanish,
+    /// The BIP39 Portuguese wordlist, 2048 words. It needs the **wordlist-pt** feature.
+    #[cfg(feature = "wordlist-pt")]
+    Portuguese,
+    /// The BIP39 Italian wordlist, 2048 words. It needs the **wordlist-it** feature.
+    #[cfg(feature = "wordlist-it")]
+    Italian,
+    /// The BIP39 Czech wordlist, 2048 words. It needs the **wordlist-cs** feature.
+    #[cfg(feature = "wordlist-cs")]
+    Czech,
+    /// The BIP39 Japanese wordlist romanized with Hepburn consonants and long vowels spelled as typed with a kana input method, like `aikokushin` and `shihyou`, 2048 words. It needs the **wordlist-ja-romaji** feature.
+    #[cfg(feature = "wordlist-ja-romaji")]
+    JapaneseRomaji,
+    /// A wordlist of your own, like a diceware list of 7776 words. The words must be unique, so that the entropy is exact.
+    Custom(&'static [&'static str]),
+}
+
+impl Wordlist {
+    /// The words of this list. The built-in lists are sorted.
+    #[inline]
+    pub const fn words(self) -> &'static [&'static str] {
+        match self {
+            Wordlist::English => ENGLISH,
+            #[cfg(feature = "wordlist-de")]
+            Wordlist::German => GERMAN,
+            #[cfg(feature = "wordlist-fr")]
+            Wordlist::French => FRENCH,
+            #[cfg(feature = "wordlist-es")]
+            Wordlist::Spanish => SPANISH,
+            #[cfg(feature = "wordlist-pt")]
+            Wordlist::Portuguese => PORTUGUESE,
+            #[cfg(feature = "wordlist-it")]
+            Wordlist::Italian => ITALIAN,
+            #[cfg(feature = "wordlist-cs")]
+            Wordlist::Czech => CZECH,
+            #[cfg(feature = "wordlist-ja-romaji")]
+            Wordlist::JapaneseRomaji => JAPANESE_ROMAJI,
+            Wordlist::Custom(words) => words,
+        }
+    }
+
+    /// The entropy of a word picked uniformly from this list, in bits.
+    #[inline]
+    pub fn entropy_per_word(self) -> f64 {
+        (self.words().len() as f64).log2()
+    }
+}
+
+/// This struct can help you generate passphrases, like `correct-horse-battery-staple`, by picking words uniformly from a `Wordlist`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassphraseGenerator {
+    /// The count of words of the generated passphrases.
+    ///
+    /// Default: `6`
+    pub words:     usize,
+    /// The separator between words.
+    ///
+    /// Default: `'-'`
+    pub separator: char,
+    /// The wordlist to pick words from.
+    ///
+    /// Default: `Wordlist::English`
+    pub wordlist:  Wordlist,
+}
+
+impl PassphraseGenerator {
+    /// Create a `PassphraseGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// PassphraseGenerator {
+    ///     words: 6,
+    ///     separator: '-',
+    ///     wordlist: Wordlist::English,
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> PassphraseGenerator {
+        PassphraseGenerator {
+            words: 6, separator: '-', wordlist: Wordlist::English
+        }
+    }
+
+    /// The count of words of the generated passphrases.
+    #[inline]
+    pub const fn words(mut self, words: usize) -> PassphraseGenerator {
+        self.words = words;
+
+        self
+    }
+
+    /// The separator between words.
+    #[inline]
+    pub const fn separator(mut self, separator: char) -> PassphraseGenerator {
+        self.separator = separator;
+
+        self
+    }
+
+    /// The wordlist to pick words from.
+    #[inline]
+    pub const fn wordlist(mut self, wordlist: Wordlist) -> PassphraseGenerator {
+        self.wordlist = wordlist;
+
+        self
+    }
+}
+
+impl PassphraseGenerator {
+    fn check_words(&self) -> Result<&'static [&'static str], &'static str> {
+        if self.words == 0 {
+            return Err("The count of words cannot be 0.");
+        }
+
+        let words = self.wordlist.words();
+
+        if words.is_empty() {
+            return Err("The wordlist cannot be empty.");
+        }
+
+        if let Wordlist::Custom(words) = self.wordlist {
+            let mut sorted = words.to_vec();
+
+            sorted.sort_unstable();
+
+            if sorted.windows(2).any(|w| w[0] == w[1]) {
+                return Err("The words of the wordlist must be unique.");
+            }
+        }
+
+        Ok(words)
+    }
+
+    /// The entropy of the generated passphrases, in bits.
+    #[inline]
+    pub fn entropy(&self) -> f64 {
+        self.words as f64 * self.wordlist.entropy_per_word()
+    }
+
+    /// Generate random passphrases.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        let words = self.check_words()?;
+
+        let mut rng = rand::rng();
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let mut passphrase = String::new();
+
+            for i in 0..self.words {
+                if i > 0 {
+                    passphrase.push(self.separator);
+                }
+
+                passphrase.push_str(words[rng.random_range(..words.len())]);
+            }
+
+            result.push(passphrase);
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random passphrase.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+
+    /// Generate a passphrase from a user-supplied source of entropy, like dice rolls, instead of the CSPRNG. Each word is picked without bias.
+    ///
+    /// If the input runs out, nothing is consumed, and the error reports the count of additional rolls or bytes needed to finish if none of them is rejected.
+    pub fn generate_with_entropy(
+        &self,
+        entropy: &mut ExternalEntropy,
+    ) -> Result<String, ExternalEntropyError> {
+        let words = self.check_words()?;
+
+        entropy.generate(|entropy| {
+            let mut passphrase = String::new();
+
+            for i in 0..self.words {
+                if i > 0 {
+                    passphrase.push(self.separator);
+                }
+
+                passphrase.push_str(words[entropy.pick_nth(words.len(), i, self.words)?]);
+            }
+
+            Ok(passphrase)
+        })
+    }
+}
+
+impl Default for PassphraseGenerator {
+    #[inline]
+    fn default() -> PassphraseGenerator {
+        PassphraseGenerator::new()
+    }
+}
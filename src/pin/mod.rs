@@ -0,0 +1,498 @@
+use std::collections::HashSet;
+
+use rand::RngExt;
+
+use crate::{ExternalEntropy, ExternalEntropyError};
+
+static COMMON_PINS: &[&str] =
+    &include!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/common-pins.json"));
+
+const STEP_OTHER: usize = 0;
+const STEP_SAME: usize = 1;
+const STEP_ASCENDING: usize = 2;
+const STEP_DESCENDING: usize = 3;
+
+#[inline]
+fn step(last: u8, current: u8) -> usize {
+    if current == last {
+        STEP_SAME
+    } else if current == last + 1 {
+        STEP_ASCENDING
+    } else if current + 1 == last {
+        STEP_DESCENDING
+    } else {
+        STEP_OTHER
+    }
+}
+
+#[inline]
+const fn days_in_month(month: u32) -> u32 {
+    match month {
+        2 => 29,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[inline]
+fn is_month_day(month: u32, day: u32) -> bool {
+    (1..=12).contains(&month) && day >= 1 && day <= days_in_month(month)
+}
+
+#[inline]
+fn is_year(year: u32) -> bool {
+    (1900..=2099).contains(&year)
+}
+
+/// The date layouts checked for each length, as `(year digits, month offset, day offset, year offset)`. `usize::MAX` means the field is absent.
+fn date_layouts(length: usize) -> &'static [(usize, usize, usize, usize)] {
+    const NONE: usize = usize::MAX;
+
+    match length {
+        // MMDD, DDMM, YYYY
+        4 => &[(0, 0, 2, NONE), (0, 2, 0, NONE), (4, NONE, NONE, 0)],
+        // MMDDYY, DDMMYY, YYMMDD, MMYYYY, YYYYMM
+        6 => &[(2, 0, 2, 4), (2, 2, 0, 4), (2, 2, 4, 0), (4, 0, NONE, 2), (4, 4, NONE, 0)],
+        // MMDDYYYY, DDMMYYYY, YYYYMMDD
+        8 => &[(4, 0, 2, 4), (4, 2, 0, 4), (4, 4, 6, 0)],
+        _ => &[],
+    }
+}
+
+#[inline]
+fn number(digits: &[u8]) -> u32 {
+    digits.iter().fold(0, |n, &d| n * 10 + d as u32)
+}
+
+fn is_date_like(digits: &[u8]) -> bool {
+    date_layouts(digits.len()).iter().any(|&(year_digits, month, day, year)| {
+        let month_day_ok = match (month, day) {
+            (usize::MAX, _) => true,
+            (month, usize::MAX) => (1..=12).contains(&number(&digits[month..month + 2])),
+            (month, day) => {
+                is_month_day(number(&digits[month..month + 2]), number(&digits[day..day + 2]))
+            },
+        };
+
+        let year_ok = match year_digits {
+            4 => is_year(number(&digits[year..year + 4])),
+            _ => true,
+        };
+
+        month_day_ok && year_ok
+    })
+}
+
+/// This struct can help you generate PINs while excluding weak ones, such as `1234`, `0000`, `1111` and `1990`.
+///
+/// A PIN is considered weak if any of the following rules enabled matches it.
+///
+/// * It has more than `max_repeated_digits` identical digits in a row.
+/// * It has an ascending or descending run, like `345` or `987`, longer than `max_sequence_length` digits.
+/// * It looks like a year or a date. For 4-digit PINs, the layouts are `MMDD`, `DDMM` and `YYYY`. For 6-digit PINs, they are `MMDDYY`, `DDMMYY`, `YYMMDD`, `MMYYYY` and `YYYYMM`. For 8-digit PINs, they are `MMDDYYYY`, `DDMMYYYY` and `YYYYMMDD`. A `YYYY` year is between 1900 and 2099.
+/// * It is in the embedded list of frequently chosen PINs.
+///
+/// The PINs which are not weak are picked uniformly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinGenerator {
+    /// The length of the generated PINs.
+    ///
+    /// Default: `6`
+    pub length:              usize,
+    /// The maximum count of identical digits in a row.
+    ///
+    /// Default: `2`
+    pub max_repeated_digits: usize,
+    /// The maximum length of an ascending or descending run of digits.
+    ///
+    /// Default: `2`
+    pub max_sequence_length: usize,
+    /// Whether to exclude year-like and date-like PINs.
+    ///
+    /// Default: `true`
+    pub exclude_dates:       bool,
+    /// Whether to exclude frequently chosen PINs.
+    ///
+    /// Default: `true`
+    pub exclude_common:      bool,
+}
+
+impl PinGenerator {
+    /// Create a `PinGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// PinGenerator {
+    ///     length: 6,
+    ///     max_repeated_digits: 2,
+    ///     max_sequence_length: 2,
+    ///     exclude_dates: true,
+    ///     exclude_common: true,
+    /// }
+    /// ```
+    pub const fn new() -> PinGenerator {
+        PinGenerator {
+            length:              6,
+            max_repeated_digits: 2,
+            max_sequence_length: 2,
+            exclude_dates:       true,
+            exclude_common:      true,
+        }
+    }
+
+    /// The length of the generated PINs.
+    pub const fn length(mut self, length: usize) -> PinGenerator {
+        self.length = length;
+
+        self
+    }
+
+    /// The maximum count of identical digits in a row.
+    pub const fn max_repeated_digits(mut self, max_repeated_digits: usize) -> PinGenerator {
+        self.max_repeated_digits = max_repeated_digits;
+
+        self
+    }
+
+    /// The maximum length of an ascending or descending run of digits.
+    pub const fn max_sequence_length(mut self, max_sequence_length: usize) -> PinGenerator {
+        self.max_sequence_length = max_sequence_length;
+
+        self
+    }
+
+    /// Whether to exclude year-like and date-like PINs.
+    pub const fn exclude_dates(mut self, exclude_dates: bool) -> PinGenerator {
+        self.exclude_dates = exclude_dates;
+
+        self
+    }
+
+    /// Whether to exclude frequently chosen PINs.
+    pub const fn exclude_common(mut self, exclude_common: bool) -> PinGenerator {
+        self.exclude_common = exclude_common;
+
+        self
+    }
+}
+
+impl PinGenerator {
+    fn run_limit(&self, step: usize) -> usize {
+        match step {
+            STEP_SAME => self.max_repeated_digits,
+            STEP_ASCENDING | STEP_DESCENDING => self.max_sequence_length,
+            _ => usize::MAX,
+        }
+    }
+
+    fn violates_run_rules(&self, digits: &[u8]) -> bool {
+        if digits.is_empty() {
+            return false;
+        }
+
+        if self.max_repeated_digits == 0 || self.max_sequence_length == 0 {
+            return true;
+        }
+
+        let mut last_step = STEP_OTHER;
+        let mut run_length = 1;
+
+        for w in digits.windows(2) {
+            let current_step = step(w[0], w[1]);
+
+            if current_step != STEP_OTHER && current_step == last_step {
+                run_length += 1;
+            } else {
+                run_length = 2;
+            }
+
+            last_step = current_step;
+
+            if current_step != STEP_OTHER && run_length > self.run_limit(current_step) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_weak_digits(&self, digits: &[u8]) -> bool {
+        if self.violates_run_rules(digits) {
+            return true;
+        }
+
+        if self.exclude_dates && is_date_like(digits) {
+            return true;
+        }
+
+        if self.exclude_common {
+            let pin: String = digits.iter().map(|&d| (b'0' + d) as char).collect();
+
+            if COMMON_PINS.binary_search(&pin.as_str()).is_ok() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Whether the input PIN is weak under the rules of this generator. A PIN whose length is not `length` or which contains non-digit characters is also weak.
+    pub fn is_weak<S: AsRef<str>>(&self, pin: S) -> bool {
+        let pin = pin.as_ref();
+
+        if pin.len() != self.length || !pin.bytes().all(|b| b.is_ascii_digit()) {
+            return true;
+        }
+
+        let digits: Vec<u8> = pin.bytes().map(|b| b - b'0').collect();
+
+        self.is_weak_digits(&digits)
+    }
+
+    /// Count the PINs which satisfy the run rules, by dynamic programming over `(last digit, last step, run length)`.
+    fn count_run_rules(&self) -> u128 {
+        if self.length == 0 || self.max_repeated_digits == 0 || self.max_sequence_length == 0 {
+            return 0;
+        }
+
+        let max_run = self.length;
+        let index = |digit: usize, step: usize, run_length: usize| {
+            (digit * 4 + step) * (max_run + 1) + run_length
+        };
+
+        let mut states = vec![0u128; 10 * 4 * (max_run + 1)];
+
+        for digit in 0..10 {
+            states[index(digit, STEP_OTHER, 1)] = 1;
+        }
+
+        for _ in 1..self.length {
+            let mut next_states = vec![0u128; states.len()];
+
+            for last in 0..10u8 {
+                for last_step in 0..4 {
+                    for run_length in 1..=max_run {
+                        let count = states[index(last as usize, last_step, run_length)];
+
+                        if count == 0 {
+                            continue;
+                        }
+
+                        for current in 0..10u8 {
+                            let current_step = step(last, current);
+
+                            let next_run_length =
+                                if current_step != STEP_OTHER && current_step == last_step {
+                                    run_length + 1
+                                } else {
+                                    2
+                                };
+
+                            if current_step != STEP_OTHER
+                                && next_run_length > self.run_limit(current_step)
+                            {
+                                continue;
+                            }
+
+                            let next = &mut next_states[index(
+                                current as usize,
+                                current_step,
+                                next_run_length.min(max_run),
+                            )];
+
+                            *next = next.saturating_add(count);
+                        }
+                    }
+                }
+            }
+
+            states = next_states;
+        }
+
+        states.iter().fold(0u128, |sum, &count| sum.saturating_add(count))
+    }
+
+    /// Collect the date-like and common PINs of this length, as numbers.
+    fn listed_pins(&self) -> HashSet<u64> {
+        let mut pins = HashSet::new();
+
+        if self.exclude_common {
+            for pin in COMMON_PINS.iter().filter(|pin| pin.len() == self.length) {
+                pins.insert(pin.parse().unwrap());
+            }
+        }
+
+        if self.exclude_dates {
+            for &(year_digits, month, day, year) in date_layouts(self.length) {
+                let years = match year_digits {
+                    4 => 1900..=2099,
+                    2 => 0..=99,
+                    _ => 0..=0,
+                };
+
+                let months = if month == usize::MAX { 0..=0 } else { 1..=12 };
+
+                for y in years.clone() {
+                    for m in months.clone() {
+                        let days = if day == usize::MAX { 0..=0 } else { 1..=days_in_month(m) };
+
+                        for d in days {
+                            let mut pin = 0u64;
+
+                            for (offset, value, width) in
+                                [(year, y, year_digits), (month, m, 2), (day, d, 2)]
+                            {
+                                if offset != usize::MAX {
+                                    pin += value as u64
+                                        * 10u64.pow((self.length - offset - width) as u32);
+                                }
+                            }
+
+                            pins.insert(pin);
+                        }
+                    }
+                }
+            }
+        }
+
+        pins
+    }
+
+    /// An upper bound of the count of the date-like and common PINs of this length, without collecting them.
+    fn listed_pins_bound(&self) -> u128 {
+        let mut bound = 0;
+
+        if self.exclude_common {
+            bound += COMMON_PINS.iter().filter(|pin| pin.len() == self.length).count() as u128;
+        }
+
+        if self.exclude_dates {
+            for &(year_digits, month, day, _) in date_layouts(self.length) {
+                let years = match year_digits {
+                    4 => 200,
+                    2 => 100,
+                    _ => 1,
+                };
+
+                let months = if month == usize::MAX { 1 } else { 12 };
+                let days = if day == usize::MAX { 1 } else { 31 };
+
+                bound += years * months * days;
+            }
+        }
+
+        bound
+    }
+
+    /// Whether any PIN is not weak. The keyspace is only counted if the listed PINs may cover all the PINs satisfying the run rules, since collecting the listed PINs is slow for long PINs.
+    fn has_strong_pins(&self) -> bool {
+        let count = self.count_run_rules();
+
+        count > self.listed_pins_bound() || (count > 0 && self.keyspace() > 0)
+    }
+
+    /// Count the PINs which are not weak, i.e. the remaining keyspace. The count saturates at `u128::MAX`.
+    pub fn keyspace(&self) -> u128 {
+        let mut keyspace = self.count_run_rules();
+
+        if keyspace == 0 {
+            return 0;
+        }
+
+        let mut digits = vec![0u8; self.length];
+
+        for pin in self.listed_pins() {
+            let mut n = pin;
+
+            for d in digits.iter_mut().rev() {
+                *d = (n % 10) as u8;
+                n /= 10;
+            }
+
+            if !self.violates_run_rules(&digits) {
+                keyspace -= 1;
+            }
+        }
+
+        keyspace
+    }
+
+    /// The entropy of the generated PINs, in bits, which is the base-2 logarithm of the keyspace.
+    #[inline]
+    pub fn entropy(&self) -> f64 {
+        (self.keyspace() as f64).log2()
+    }
+
+    /// Generate random PINs.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        if self.length == 0 {
+            return Err("The length of PINs cannot be 0.");
+        }
+
+        if !self.has_strong_pins() {
+            return Err("No PIN satisfies the rules.");
+        }
+
+        let mut rng = rand::rng();
+        let mut digits = vec![0u8; self.length];
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            loop {
+                for d in digits.iter_mut() {
+                    *d = rng.random_range(..10);
+                }
+
+                if !self.is_weak_digits(&digits) {
+                    break;
+                }
+            }
+
+            result.push(digits.iter().map(|&d| (b'0' + d) as char).collect());
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random PIN.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+
+    /// Generate a PIN from a user-supplied source of entropy, like dice rolls, instead of the CSPRNG. Each digit is picked without bias, and weak PINs are rejected and picked again from the following input.
+    ///
+    /// If the input runs out, nothing is consumed, and the error reports the count of additional rolls or bytes needed to finish if none of them is rejected.
+    pub fn generate_with_entropy(
+        &self,
+        entropy: &mut ExternalEntropy,
+    ) -> Result<String, ExternalEntropyError> {
+        if self.length == 0 {
+            return Err("The length of PINs cannot be 0.".into());
+        }
+
+        if !self.has_strong_pins() {
+            return Err("No PIN satisfies the rules.".into());
+        }
+
+        let mut digits = vec![0u8; self.length];
+
+        entropy.generate(|entropy| {
+            loop {
+                for (i, d) in digits.iter_mut().enumerate() {
+                    *d = entropy.pick_nth(10, i, self.length)? as u8;
+                }
+
+                if !self.is_weak_digits(&digits) {
+                    return Ok(digits.iter().map(|&d| (b'0' + d) as char).collect());
+                }
+            }
+        })
+    }
+}
+
+impl Default for PinGenerator {
+    #[inline]
+    fn default() -> PinGenerator {
+        PinGenerator::new()
+    }
+}
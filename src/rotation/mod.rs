@@ -0,0 +1,171 @@
+use crate::PasswordGenerator;
+
+/// The maximum count of passwords generated by `PasswordRotator::generate` before it gives up.
+const MAX_ATTEMPTS: usize = 10000;
+
+/// The Levenshtein distance between two strings, in characters.
+pub(crate) fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+
+            diagonal = row[j + 1];
+
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// The length of the longest common substring of two strings, in characters.
+fn longest_common_substring(a: &[char], b: &[char]) -> usize {
+    let mut row = vec![0usize; b.len() + 1];
+    let mut longest = 0;
+
+    for ca in a {
+        // iterate backwards so that `row[j]` still holds the value of the previous row
+        for (j, cb) in b.iter().enumerate().rev() {
+            row[j + 1] = if ca == cb { row[j] + 1 } else { 0 };
+
+            longest = longest.max(row[j + 1]);
+        }
+    }
+
+    longest
+}
+
+#[inline]
+fn fold_case(s: &str) -> Vec<char> {
+    s.chars().flat_map(char::to_lowercase).collect()
+}
+
+/// This struct can help you rotate passwords, by generating a new password which is sufficiently different from the previous ones, or by checking a replacement chosen by a person.
+///
+/// A new password is sufficiently different from a previous one if their edit distance is at least `min_edit_distance` and their longest common substring is at most `max_common_substring` characters long. Both are compared case-insensitively, so that `Summer2024` and `SUMMER2025` are considered nearly identical.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordRotator {
+    /// The generator of new passwords.
+    ///
+    /// Default: `PasswordGenerator::new()`
+    pub generator:            PasswordGenerator,
+    /// The minimum Levenshtein distance between the new password and each previous one.
+    ///
+    /// Default: `4`
+    pub min_edit_distance:    usize,
+    /// The maximum length of a substring shared by the new password and each previous one.
+    ///
+    /// Default: `3`
+    pub max_common_substring: usize,
+}
+
+impl PasswordRotator {
+    /// Create a `PasswordRotator` instance.
+    ///
+    /// ```rust,ignore
+    /// PasswordRotator {
+    ///     generator: PasswordGenerator::new(),
+    ///     min_edit_distance: 4,
+    ///     max_common_substring: 3,
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> PasswordRotator {
+        PasswordRotator {
+            generator:            PasswordGenerator::new(),
+            min_edit_distance:    4,
+            max_common_substring: 3,
+        }
+    }
+
+    /// The generator of new passwords.
+    #[inline]
+    pub fn generator(mut self, generator: PasswordGenerator) -> PasswordRotator {
+        self.generator = generator;
+
+        self
+    }
+
+    /// The minimum Levenshtein distance between the new password and each previous one.
+    #[inline]
+    pub const fn min_edit_distance(mut self, min_edit_distance: usize) -> PasswordRotator {
+        self.min_edit_distance = min_edit_distance;
+
+        self
+    }
+
+    /// The maximum length of a substring shared by the new password and each previous one.
+    #[inline]
+    pub const fn max_common_substring(mut self, max_common_substring: usize) -> PasswordRotator {
+        self.max_common_substring = max_common_substring;
+
+        self
+    }
+}
+
+impl PasswordRotator {
+    /// Check whether the new password is sufficiently different from each previous password.
+    pub fn check<S: AsRef<str>, P: AsRef<str>>(
+        &self,
+        previous: &[P],
+        password: S,
+    ) -> Result<(), &'static str> {
+        let password = fold_case(password.as_ref());
+
+        for p in previous {
+            let p = fold_case(p.as_ref());
+
+            if edit_distance(&password, &p) < self.min_edit_distance {
+                return Err(
+                    "The new password is too close to a previous password in edit distance."
+                );
+            }
+
+            if longest_common_substring(&password, &p) > self.max_common_substring {
+                return Err(
+                    "The new password shares too long a substring with a previous password."
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the new password is sufficiently different from each previous password.
+    #[inline]
+    pub fn is_different_enough<S: AsRef<str>, P: AsRef<str>>(
+        &self,
+        previous: &[P],
+        password: S,
+    ) -> bool {
+        self.check(previous, password).is_ok()
+    }
+
+    /// Generate a random password which is sufficiently different from each previous password. Passwords are generated until one passes the check, so the thresholds should leave most passwords of the generator acceptable.
+    pub fn generate<P: AsRef<str>>(&self, previous: &[P]) -> Result<String, &'static str> {
+        let mut iter = self.generator.try_iter()?;
+
+        for _ in 0..MAX_ATTEMPTS {
+            let password = iter.next().unwrap();
+
+            if self.is_different_enough(previous, &password) {
+                return Ok(password);
+            }
+        }
+
+        Err("Cannot generate a password sufficiently different from the previous passwords.")
+    }
+}
+
+impl Default for PasswordRotator {
+    #[inline]
+    fn default() -> PasswordRotator {
+        PasswordRotator::new()
+    }
+}
@@ -0,0 +1,1029 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::Range,
+    str::FromStr,
+};
+
+use rand::{Rng, RngExt};
+
+use crate::{PasswordGenerator, offensive::CONVERGENCE_TOLERANCE};
+
+/// The length of generated passwords if the rules allow it.
+const DEFAULT_LENGTH: usize = 16;
+
+/// The maximum count of distinct required sets for generating passwords, since the table for generating passwords grows exponentially with it.
+const MAX_REQUIRED_SETS: usize = 16;
+
+type CharClass = fn(char) -> bool;
+
+static CLASSES: [(&str, CharClass); 5] = [
+    ("ascii-printable", |c| matches!(c, ' '..='~')),
+    ("upper", |c| c.is_ascii_uppercase()),
+    ("lower", |c| c.is_ascii_lowercase()),
+    ("digit", |c| c.is_ascii_digit()),
+    ("special", |c| c == ' ' || c.is_ascii_punctuation()),
+];
+
+#[inline]
+fn ascii_printable() -> impl Iterator<Item = char> {
+    ' '..='~'
+}
+
+#[inline]
+fn normalize(set: &mut Vec<char>) {
+    set.sort_unstable();
+    set.dedup();
+}
+
+/// An error which can be returned when parsing `passwordrules`. It points at the offending span of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordRulesError {
+    reason: &'static str,
+    span:   Range<usize>,
+}
+
+impl PasswordRulesError {
+    #[inline]
+    fn new(reason: &'static str, span: Range<usize>) -> PasswordRulesError {
+        PasswordRulesError {
+            reason,
+            span,
+        }
+    }
+
+    /// The reason of the error.
+    #[inline]
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+
+    /// The byte range of the offending part of the input.
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for PasswordRulesError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.reason, self.span.start, self.span.end)
+    }
+}
+
+impl Error for PasswordRulesError {}
+
+/// An error which can be returned when intersecting password rules. It names the conflicting rules by their indices. They are usually a pair, but can be more if no pair of them conflicts, or a single one if it cannot be satisfied by itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordRulesConflict {
+    rules:  Vec<usize>,
+    reason: &'static str,
+}
+
+impl PasswordRulesConflict {
+    /// The indices of the conflicting rules, sorted. The rules without any one of them do not conflict.
+    #[inline]
+    pub fn rules(&self) -> &[usize] {
+        &self.rules
+    }
+
+    /// The index of the first conflicting rules.
+    #[inline]
+    pub fn first(&self) -> usize {
+        self.rules[0]
+    }
+
+    /// The index of the second conflicting rules, or of the first one if it cannot be satisfied by itself.
+    #[inline]
+    pub fn second(&self) -> usize {
+        self.rules[1.min(self.rules.len() - 1)]
+    }
+
+    /// The reason of the conflict.
+    #[inline]
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl Display for PasswordRulesConflict {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.rules.as_slice() {
+            [only] => write!(f, "rules #{only} cannot be satisfied: {}", self.reason),
+            [first, second] => {
+                write!(f, "rules #{first} and rules #{second} conflict: {}", self.reason)
+            },
+            rules => {
+                let (last, init) = rules.split_last().unwrap();
+
+                f.write_str("rules")?;
+
+                for index in init {
+                    write!(f, " #{index},")?;
+                }
+
+                write!(f, " and #{last} conflict: {}", self.reason)
+            },
+        }
+    }
+}
+
+impl Error for PasswordRulesConflict {}
+
+struct Parser<'a> {
+    input:    &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    /// The span of the next character, or an empty span at the end of the input.
+    #[inline]
+    fn next_span(&self) -> Range<usize> {
+        self.position..self.position + self.peek().map(char::len_utf8).unwrap_or(0)
+    }
+
+    #[inline]
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+
+            self.position += c.len_utf8();
+        }
+    }
+
+    fn take_identifier(&mut self) -> Range<usize> {
+        let start = self.position;
+
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '-') {
+                break;
+            }
+
+            self.position += 1;
+        }
+
+        start..self.position
+    }
+
+    fn parse_number(&mut self) -> Result<(usize, Range<usize>), PasswordRulesError> {
+        let start = self.position;
+
+        while let Some(c) = self.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+
+            self.position += 1;
+        }
+
+        let span = start..self.position;
+
+        if span.is_empty() {
+            let token_length = self.input[start..]
+                .find(|c: char| c.is_whitespace() || c == ';')
+                .unwrap_or(self.input.len() - start);
+
+            return Err(PasswordRulesError::new("expected a number", start..start + token_length));
+        }
+
+        match self.input[span.clone()].parse() {
+            Ok(n) => Ok((n, span)),
+            Err(_) => Err(PasswordRulesError::new("the number is too large", span)),
+        }
+    }
+
+    fn parse_custom_class(&mut self, set: &mut Vec<char>) -> Result<(), PasswordRulesError> {
+        let start = self.position;
+
+        // skip `[`
+        self.position += 1;
+
+        let mut first = true;
+
+        loop {
+            let c = match self.peek() {
+                Some(c) => c,
+                None => {
+                    return Err(PasswordRulesError::new(
+                        "unterminated character class",
+                        start..self.position,
+                    ));
+                },
+            };
+
+            if c == ']' && !first {
+                self.position += 1;
+
+                return Ok(());
+            }
+
+            if !matches!(c, ' '..='~') {
+                return Err(PasswordRulesError::new(
+                    "only ASCII printable characters are allowed in a character class",
+                    self.next_span(),
+                ));
+            }
+
+            set.push(c);
+
+            self.position += 1;
+            first = false;
+        }
+    }
+
+    fn parse_classes(&mut self) -> Result<Vec<char>, PasswordRulesError> {
+        let mut set = Vec::new();
+
+        loop {
+            self.skip_whitespaces();
+
+            if self.peek() == Some('[') {
+                self.parse_custom_class(&mut set)?;
+            } else {
+                let span = self.take_identifier();
+
+                if span.is_empty() {
+                    return Err(PasswordRulesError::new(
+                        "expected a character class",
+                        self.next_span(),
+                    ));
+                }
+
+                let name = self.input[span.clone()].to_ascii_lowercase();
+
+                // `unicode` is treated as `ascii-printable`, because generated passwords should be typeable
+                let class = match name.as_str() {
+                    "unicode" => CLASSES[0].1,
+                    name => match CLASSES.iter().find(|(class_name, _)| *class_name == name) {
+                        Some((_, class)) => *class,
+                        None => {
+                            return Err(PasswordRulesError::new("unknown character class", span));
+                        },
+                    },
+                };
+
+                set.extend(ascii_printable().filter(|&c| class(c)));
+            }
+
+            self.skip_whitespaces();
+
+            if !self.eat(',') {
+                break;
+            }
+        }
+
+        normalize(&mut set);
+
+        Ok(set)
+    }
+}
+
+/// Password rules in the `passwordrules` attribute syntax, like `minlength: 12; maxlength: 20; required: upper; required: digit; allowed: [-_.]; max-consecutive: 2`. It extends the character classes of `PasswordGenerator` with custom character sets and a limit of consecutive identical characters.
+///
+/// The supported properties are `required`, `allowed`, `minlength`, `maxlength` and `max-consecutive`. The supported character classes are `upper`, `lower`, `digit`, `special` (ASCII printable characters which are not letters or numbers, including the space), `ascii-printable`, `unicode` (treated as `ascii-printable`) and custom sets like `[-_.]`. If neither `required` nor `allowed` is specified, all ASCII printable characters are allowed. If a property appears more than once, the strictest value is used.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PasswordRules {
+    /// The minimum length of passwords.
+    ///
+    /// Default: `0`
+    pub min_length:      usize,
+    /// The maximum length of passwords.
+    ///
+    /// Default: `None`
+    pub max_length:      Option<usize>,
+    /// The maximum count of identical characters in a row.
+    ///
+    /// Default: `None`
+    pub max_consecutive: Option<usize>,
+    /// Sets of characters. Passwords must contain at least one character of each set.
+    ///
+    /// Default: `[]`
+    pub required:        Vec<Vec<char>>,
+    /// Characters which are allowed in passwords, besides the required ones.
+    ///
+    /// Default: `[]`
+    pub allowed:         Vec<char>,
+}
+
+impl FromStr for PasswordRules {
+    type Err = PasswordRulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = PasswordRules::default();
+        let mut parser = Parser {
+            input: s, position: 0
+        };
+
+        let mut min_length_span = 0..0;
+        let mut max_length_span = 0..0;
+
+        loop {
+            parser.skip_whitespaces();
+
+            if parser.peek().is_none() {
+                break;
+            }
+
+            if parser.eat(';') {
+                continue;
+            }
+
+            let name_span = parser.take_identifier();
+
+            if name_span.is_empty() {
+                return Err(PasswordRulesError::new(
+                    "expected a property name",
+                    parser.next_span(),
+                ));
+            }
+
+            parser.skip_whitespaces();
+
+            if !parser.eat(':') {
+                return Err(PasswordRulesError::new("expected `:`", parser.next_span()));
+            }
+
+            parser.skip_whitespaces();
+
+            match s[name_span.clone()].to_ascii_lowercase().as_str() {
+                "required" => {
+                    let set = parser.parse_classes()?;
+
+                    rules.required.push(set);
+                },
+                "allowed" => {
+                    let set = parser.parse_classes()?;
+
+                    rules.allowed.extend(set);
+                    normalize(&mut rules.allowed);
+                },
+                "minlength" => {
+                    let (n, span) = parser.parse_number()?;
+
+                    if n >= rules.min_length {
+                        rules.min_length = n;
+                        min_length_span = span;
+                    }
+                },
+                "maxlength" => {
+                    let (n, span) = parser.parse_number()?;
+
+                    if n == 0 {
+                        return Err(PasswordRulesError::new("maxlength must be positive", span));
+                    }
+
+                    if rules.max_length.is_none_or(|max_length| n <= max_length) {
+                        rules.max_length = Some(n);
+                        max_length_span = span;
+                    }
+                },
+                "max-consecutive" => {
+                    let (n, span) = parser.parse_number()?;
+
+                    if n == 0 {
+                        return Err(PasswordRulesError::new(
+                            "max-consecutive must be positive",
+                            span,
+                        ));
+                    }
+
+                    if rules.max_consecutive.is_none_or(|max_consecutive| n <= max_consecutive) {
+                        rules.max_consecutive = Some(n);
+                    }
+                },
+                _ => return Err(PasswordRulesError::new("unknown property", name_span)),
+            }
+
+            parser.skip_whitespaces();
+
+            if parser.peek().is_some() && !parser.eat(';') {
+                return Err(PasswordRulesError::new("expected `;`", parser.next_span()));
+            }
+        }
+
+        if let Some(max_length) = rules.max_length {
+            if rules.min_length > max_length {
+                let span = if min_length_span.start > max_length_span.start {
+                    min_length_span
+                } else {
+                    max_length_span
+                };
+
+                return Err(PasswordRulesError::new("minlength is greater than maxlength", span));
+            }
+        }
+
+        Ok(rules)
+    }
+}
+
+fn fmt_classes(f: &mut Formatter<'_>, set: &[char]) -> fmt::Result {
+    let mut set = set.to_vec();
+
+    normalize(&mut set);
+
+    let mut first = true;
+
+    for (name, class) in CLASSES.iter() {
+        if ascii_printable().filter(|&c| class(c)).all(|c| set.binary_search(&c).is_ok()) {
+            if !first {
+                f.write_str(", ")?;
+            }
+
+            f.write_str(name)?;
+            first = false;
+
+            set.retain(|&c| !class(c));
+        }
+    }
+
+    if !set.is_empty() {
+        if !first {
+            f.write_str(", ")?;
+        }
+
+        // `]` is only literal at the beginning of a custom character class
+        if let Ok(index) = set.binary_search(&']') {
+            set.remove(index);
+            set.insert(0, ']');
+        }
+
+        write!(f, "[{}]", set.iter().collect::<String>())?;
+    }
+
+    Ok(())
+}
+
+impl Display for PasswordRules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+
+        for set in self.required.iter() {
+            f.write_str(separator)?;
+            f.write_str("required: ")?;
+            fmt_classes(f, set)?;
+
+            separator = "; ";
+        }
+
+        if !self.allowed.is_empty() {
+            f.write_str(separator)?;
+            f.write_str("allowed: ")?;
+            fmt_classes(f, &self.allowed)?;
+
+            separator = "; ";
+        }
+
+        if let Some(max_consecutive) = self.max_consecutive {
+            write!(f, "{separator}max-consecutive: {max_consecutive}")?;
+
+            separator = "; ";
+        }
+
+        if self.min_length > 0 {
+            write!(f, "{separator}minlength: {}", self.min_length)?;
+
+            separator = "; ";
+        }
+
+        if let Some(max_length) = self.max_length {
+            write!(f, "{separator}maxlength: {max_length}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A table to pick passwords of a length which satisfy the rules, character by character.
+///
+/// The state after a character is the mask of the hit required sets, the group of the character and the count of times it is repeated in a row. The group and the count are only tracked if the maximum count of consecutive identical characters can be reached.
+struct RulesSampler {
+    /// The characters grouped by the required sets containing them, as `(mask, characters)`.
+    groups:    Vec<(usize, Vec<char>)>,
+    full_mask: usize,
+    /// The maximum count of consecutive identical characters, or `0` if it cannot be reached.
+    max_run:   usize,
+    /// `rows[k][state]` is proportional to the count of the ways to finish a password with `k` more characters from a state. The rows converge, so the last row is reused for bigger `k`.
+    rows:      Vec<Vec<f64>>,
+}
+
+impl RulesSampler {
+    fn new(rules: &PasswordRules, length: usize) -> Result<RulesSampler, &'static str> {
+        let mut required = rules.required.clone();
+
+        required.iter_mut().for_each(normalize);
+        required.sort_unstable();
+        required.dedup();
+
+        if required.len() > MAX_REQUIRED_SETS {
+            return Err("There are too many required character sets.");
+        }
+
+        let mut groups: Vec<(usize, Vec<char>)> = Vec::new();
+
+        for c in rules.pool() {
+            let mask = (0..required.len())
+                .filter(|&i| required[i].binary_search(&c).is_ok())
+                .fold(0, |mask, i| mask | (1 << i));
+
+            match groups.iter_mut().find(|(m, _)| *m == mask) {
+                Some((_, chars)) => chars.push(c),
+                None => groups.push((mask, vec![c])),
+            }
+        }
+
+        let mut sampler = RulesSampler {
+            groups,
+            full_mask: (1 << required.len()) - 1,
+            max_run: rules.max_consecutive.filter(|&n| n < length).unwrap_or(0),
+            rows: Vec::new(),
+        };
+
+        let states = sampler.states();
+
+        sampler.rows.push(
+            (0..states)
+                .map(|state| f64::from(sampler.mask_of(state) == sampler.full_mask))
+                .collect(),
+        );
+
+        for _ in 1..length {
+            let previous = sampler.rows.last().unwrap();
+
+            let mut row: Vec<f64> = (0..states)
+                .map(|state| {
+                    let mut count = 0.0;
+
+                    sampler.for_each_transition(state, |_, ways, next| {
+                        count += ways as f64 * previous[next];
+                    });
+
+                    count
+                })
+                .collect();
+
+            // normalize to keep the counts in the range of `f64`
+            let sum: f64 = row.iter().sum();
+
+            if sum > 0.0 {
+                row.iter_mut().for_each(|count| *count /= sum);
+            }
+
+            if row.iter().zip(previous).all(|(a, b)| (a - b).abs() <= CONVERGENCE_TOLERANCE) {
+                break;
+            }
+
+            sampler.rows.push(row);
+        }
+
+        if sampler.weights(sampler.state(0, sampler.groups.len(), 0), length).is_empty() {
+            return Err("The rules cannot be satisfied.");
+        }
+
+        Ok(sampler)
+    }
+
+    #[inline]
+    fn states(&self) -> usize {
+        if self.max_run == 0 {
+            self.full_mask + 1
+        } else {
+            (self.full_mask + 1) * (self.groups.len() + 1) * (self.max_run + 1)
+        }
+    }
+
+    /// The state after a character of a group which is repeated `run` times in a row. The group is `groups.len()` at the beginning.
+    #[inline]
+    fn state(&self, mask: usize, group: usize, run: usize) -> usize {
+        if self.max_run == 0 {
+            mask
+        } else {
+            (mask * (self.groups.len() + 1) + group) * (self.max_run + 1) + run
+        }
+    }
+
+    #[inline]
+    fn mask_of(&self, state: usize) -> usize {
+        if self.max_run == 0 { state } else { state / (self.max_run + 1) / (self.groups.len() + 1) }
+    }
+
+    /// Call `f(group, ways, next_state)` for the next characters from a state, where the group is `None` for repeating the last character.
+    fn for_each_transition<F: FnMut(Option<usize>, usize, usize)>(&self, state: usize, mut f: F) {
+        let mask = self.mask_of(state);
+
+        let group = if self.max_run == 0 {
+            self.groups.len()
+        } else {
+            let run = state % (self.max_run + 1);
+            let group = state / (self.max_run + 1) % (self.groups.len() + 1);
+
+            if group < self.groups.len() && run < self.max_run {
+                f(None, 1, self.state(mask, group, run + 1));
+            }
+
+            group
+        };
+
+        for (h, (group_mask, chars)) in self.groups.iter().enumerate() {
+            // the last character is counted by the transition which repeats it
+            let ways = chars.len() - usize::from(self.max_run > 0 && h == group);
+
+            if ways > 0 {
+                f(Some(h), ways, self.state(mask | group_mask, h, 1));
+            }
+        }
+    }
+
+    /// The possible next characters from a state with `remaining` characters to pick, as `(group, ways, next_state, weight)`.
+    fn weights(&self, state: usize, remaining: usize) -> Vec<(Option<usize>, usize, usize, f64)> {
+        let row = &self.rows[(remaining - 1).min(self.rows.len() - 1)];
+
+        let mut weights = Vec::with_capacity(self.groups.len() + 1);
+
+        self.for_each_transition(state, |group, ways, next| {
+            let weight = ways as f64 * row[next];
+
+            if weight > 0.0 {
+                weights.push((group, ways, next, weight));
+            }
+        });
+
+        weights
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R, length: usize) -> String {
+        let mut password = String::with_capacity(length);
+        let mut state = self.state(0, self.groups.len(), 0);
+        let mut last = None;
+
+        for position in 0..length {
+            let weights = self.weights(state, length - position);
+
+            let total: f64 = weights.iter().map(|&(.., weight)| weight).sum();
+            let mut r = rng.random::<f64>() * total;
+
+            // the last possible transition is picked if `r` is not consumed because of rounding
+            let mut picked = weights[weights.len() - 1];
+
+            for &transition in weights.iter() {
+                if r < transition.3 {
+                    picked = transition;
+                    break;
+                }
+
+                r -= transition.3;
+            }
+
+            let (group, ways, next, _) = picked;
+
+            let c = match group {
+                None => last.unwrap(),
+                Some(h) => {
+                    let mut chars = self.groups[h].1.iter().copied();
+
+                    if self.max_run > 0 {
+                        chars.filter(|&c| Some(c) != last).nth(rng.random_range(..ways)).unwrap()
+                    } else {
+                        chars.nth(rng.random_range(..ways)).unwrap()
+                    }
+                },
+            };
+
+            password.push(c);
+            last = Some(c);
+            state = next;
+        }
+
+        password
+    }
+}
+
+impl PasswordRules {
+    /// All characters which can appear in passwords.
+    pub fn pool(&self) -> Vec<char> {
+        let mut pool: Vec<char> =
+            self.allowed.iter().chain(self.required.iter().flatten()).copied().collect();
+
+        if pool.is_empty() && self.required.is_empty() {
+            pool.extend(ascii_printable());
+        }
+
+        normalize(&mut pool);
+
+        pool
+    }
+
+    /// The length of generated passwords. It is 16, or the nearest length with which the rules can be satisfied, taking the characters needed by the required sets and the maximum count of consecutive identical characters into account.
+    pub fn length(&self) -> usize {
+        let min_length = self
+            .min_length
+            .max(min_hitting_count(&self.required).unwrap_or(self.required.len()))
+            .max(1);
+
+        let mut max_length = self.max_length;
+
+        // any length can be filled by alternating two characters, but a single character can only be repeated
+        if self.pool().len() <= 1 || self.max_consecutive == Some(0) {
+            max_length = max_length.into_iter().chain(self.max_consecutive).min();
+        }
+
+        let length = DEFAULT_LENGTH.max(min_length);
+
+        match max_length {
+            Some(max_length) => length.min(max_length),
+            None => length,
+        }
+    }
+
+    /// Whether the password satisfies the rules.
+    #[inline]
+    pub fn is_satisfied_by<S: AsRef<str>>(&self, password: S) -> bool {
+        self.is_satisfied_by_with_pool(password.as_ref(), &self.pool())
+    }
+
+    fn is_satisfied_by_with_pool(&self, password: &str, pool: &[char]) -> bool {
+        let mut length = 0;
+        let mut last = None;
+        let mut consecutive = 0;
+
+        for c in password.chars() {
+            if pool.binary_search(&c).is_err() {
+                return false;
+            }
+
+            if last == Some(c) {
+                consecutive += 1;
+            } else {
+                consecutive = 1;
+                last = Some(c);
+            }
+
+            if self.max_consecutive.is_some_and(|max_consecutive| consecutive > max_consecutive) {
+                return false;
+            }
+
+            length += 1;
+        }
+
+        length >= self.min_length
+            && self.max_length.is_none_or(|max_length| length <= max_length)
+            && self.required.iter().all(|set| password.chars().any(|c| set.contains(&c)))
+    }
+
+    /// Generate random passwords. Every password is picked uniformly from the passwords of `length()` characters which satisfy the rules.
+    ///
+    /// The characters are picked one by one, each in proportion to the count of the passwords satisfying the rules which start with the picked characters, so no password is rejected, however tight the rules are. The rules can have at most 16 distinct required sets. `PasswordGenerator` is not used, since its pool is made of fixed kinds of characters, which cannot express arbitrary allowed and required sets.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        let (sampler, length) = self.sampler()?;
+
+        let mut rng = rand::rng();
+
+        let result = (0..count).map(|_| sampler.sample(&mut rng, length)).collect();
+
+        Ok(result)
+    }
+
+    /// Generate a random password.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+
+    /// The table to pick passwords of `length()` characters, with the length.
+    fn sampler(&self) -> Result<(RulesSampler, usize), &'static str> {
+        if self.max_length.is_some_and(|max_length| self.min_length > max_length) {
+            return Err("The minimum length is greater than the maximum length.");
+        }
+
+        if self.required.iter().any(|set| set.is_empty()) {
+            return Err("A required character set is empty.");
+        }
+
+        let length = self.length();
+
+        if length == 0 {
+            return Err("The length of passwords cannot be 0.");
+        }
+
+        // a single character cannot be repeated up to the minimum length
+        if length < self.min_length {
+            return Err("The rules cannot be satisfied.");
+        }
+
+        Ok((RulesSampler::new(self, length)?, length))
+    }
+}
+
+impl TryFrom<&PasswordGenerator> for PasswordRules {
+    type Error = &'static str;
+
+    /// Convert the settings of a `PasswordGenerator` to password rules. The length becomes both the minimum and the maximum length, or the length range becomes them, regardless of the length distribution. If the generator is strict, every kind of characters is required. Password rules cannot express offensive words or confusable pairs, so `exclude_offensive_words` and `transcription_safe` are ignored.
+    fn try_from(generator: &PasswordGenerator) -> Result<Self, Self::Error> {
+        let iter = generator.try_iter()?;
+
+        let mut rules = PasswordRules {
+            min_length: iter.length,
+            max_length: Some(iter.max_length),
+            ..PasswordRules::default()
+        };
+
+        if iter.strict {
+            rules.required = iter.pool.iter().map(|slice| slice.to_vec()).collect();
+
+            for set in rules.required.iter_mut() {
+                normalize(set);
+            }
+        } else {
+            rules.allowed = iter.pool.concat();
+
+            normalize(&mut rules.allowed);
+        }
+
+        Ok(rules)
+    }
+}
+
+impl TryFrom<PasswordGenerator> for PasswordRules {
+    type Error = &'static str;
+
+    #[inline]
+    fn try_from(generator: PasswordGenerator) -> Result<Self, Self::Error> {
+        PasswordRules::try_from(&generator)
+    }
+}
+
+/// The minimum count of characters which hit every set, or `None` if a set is empty.
+fn min_hitting_count(sets: &[Vec<char>]) -> Option<usize> {
+    // characters are only distinguished by the sets containing them
+    let mut signatures: Vec<Vec<usize>> = Vec::new();
+
+    for c in sets.iter().flatten() {
+        let signature: Vec<usize> = (0..sets.len()).filter(|&i| sets[i].contains(c)).collect();
+
+        if !signatures.contains(&signature) {
+            signatures.push(signature);
+        }
+    }
+
+    fn can_hit(signatures: &[Vec<usize>], hit: &mut [bool], budget: usize) -> bool {
+        let Some(unhit) = hit.iter().position(|&hit| !hit) else {
+            return true;
+        };
+
+        if budget == 0 {
+            return false;
+        }
+
+        for signature in signatures.iter().filter(|signature| signature.contains(&unhit)) {
+            let newly_hit: Vec<usize> = signature.iter().copied().filter(|&i| !hit[i]).collect();
+
+            newly_hit.iter().for_each(|&i| hit[i] = true);
+
+            let found = can_hit(signatures, hit, budget - 1);
+
+            newly_hit.iter().for_each(|&i| hit[i] = false);
+
+            if found {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    let mut hit = vec![false; sets.len()];
+
+    (0..=sets.len()).find(|&budget| can_hit(&signatures, &mut hit, budget))
+}
+
+impl PasswordRules {
+    /// Merge several password rules without checking whether the result can be satisfied.
+    fn merge(rules: &[&PasswordRules]) -> PasswordRules {
+        let mut allowed = rules[0].pool();
+
+        for r in rules.iter().skip(1) {
+            let pool = r.pool();
+
+            allowed.retain(|c| pool.binary_search(c).is_ok());
+        }
+
+        let mut required: Vec<Vec<char>> = Vec::new();
+
+        for set in rules.iter().flat_map(|r| r.required.iter()) {
+            let mut set = set.clone();
+
+            set.retain(|c| allowed.binary_search(c).is_ok());
+            normalize(&mut set);
+
+            if !required.contains(&set) {
+                required.push(set);
+            }
+        }
+
+        PasswordRules {
+            min_length: rules.iter().map(|r| r.min_length).max().unwrap(),
+            max_length: rules.iter().filter_map(|r| r.max_length).min(),
+            max_consecutive: rules.iter().filter_map(|r| r.max_consecutive).min(),
+            required,
+            allowed,
+        }
+    }
+
+    /// The reason why no password satisfying the rules can be generated, if any.
+    fn unsatisfiable_reason(&self) -> Option<&'static str> {
+        if self.max_length.is_some_and(|max_length| self.min_length > max_length) {
+            return Some(
+                "the minimum length of one is greater than the maximum length of the other",
+            );
+        }
+
+        if self.allowed.is_empty() {
+            return Some("the allowed characters have nothing in common");
+        }
+
+        let Some(required_count) = min_hitting_count(&self.required) else {
+            return Some("a required character set is not allowed by the rest");
+        };
+
+        if self.max_length.is_some_and(|max_length| required_count > max_length) {
+            return Some(
+                "the required character sets need more characters than the maximum length",
+            );
+        }
+
+        if self.allowed.len() == 1
+            && self.max_consecutive.is_some_and(|max_consecutive| {
+                max_consecutive < self.min_length.max(required_count)
+            })
+        {
+            return Some(
+                "the only allowed character cannot repeat as many times as the minimum length",
+            );
+        }
+
+        if self.required.len() > MAX_REQUIRED_SETS {
+            return Some("the distinct required character sets are more than can be generated");
+        }
+
+        // `Ok` of `intersection` promises that passwords can be generated
+        if self.sampler().is_err() {
+            return Some("no password of the generated length satisfies the rules");
+        }
+
+        None
+    }
+
+    /// Compute the intersection of several password rules, so that a password satisfying the result satisfies all of them. The allowed characters are the common ones, the minimum length is the maximum of the minimum lengths, the maximum length is the minimum of the maximum lengths, the maximum count of consecutive identical characters is the minimum of them, and the required sets are the union of the required sets, restricted to the allowed characters.
+    ///
+    /// If the rules cannot be satisfied at the same time, or the passwords satisfying them cannot be generated by `generate`, the returned error names the conflicting rules, which are as few as possible. So the result of `Ok` can always generate passwords.
+    pub fn intersection(rules: &[PasswordRules]) -> Result<PasswordRules, PasswordRulesConflict> {
+        if rules.is_empty() {
+            return Ok(PasswordRules::default());
+        }
+
+        let merge = |indices: &[usize]| {
+            PasswordRules::merge(&indices.iter().map(|&i| &rules[i]).collect::<Vec<_>>())
+        };
+
+        let mut indices: Vec<usize> = (0..rules.len()).collect();
+
+        let merged = merge(&indices);
+
+        if merged.unsatisfiable_reason().is_none() {
+            return Ok(merged);
+        }
+
+        // drop the rules which are not needed for the conflict, from the last one
+        for i in (0..rules.len()).rev() {
+            let rest: Vec<usize> = indices.iter().copied().filter(|&j| j != i).collect();
+
+            if !rest.is_empty() && merge(&rest).unsatisfiable_reason().is_some() {
+                indices = rest;
+            }
+        }
+
+        Err(PasswordRulesConflict {
+            reason: merge(&indices).unsatisfiable_reason().unwrap(),
+            rules:  indices,
+        })
+    }
+}
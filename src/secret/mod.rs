@@ -0,0 +1,366 @@
+use std::convert::Infallible;
+
+use rand::{Rng, RngExt};
+
+use crate::offensive::{CleanSampler, log2_count_clean};
+
+static HEX: [u8; 16] = *b"0123456789abcdef";
+static BASE32: [u8; 32] = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+static BASE64_URL: [u8; 64] = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+/// `choices[position][extra]` lists the characters allowed at a position with their next extra states.
+type Choices = Vec<Vec<Vec<(char, usize)>>>;
+
+static BASE58: [u8; 58] = *b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The encodings supported by `SecretGenerator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecretEncoding {
+    /// Lowercase hexadecimal, `0-9a-f`.
+    Hex,
+    /// The RFC 4648 base32 alphabet, `A-Z2-7`, without padding.
+    Base32,
+    /// The RFC 4648 URL and filename safe base64 alphabet, `A-Za-z0-9-_`, without padding.
+    Base64Url,
+    /// The Bitcoin base58 alphabet, which excludes `0OIl`.
+    Base58,
+}
+
+impl SecretEncoding {
+    /// The alphabet of this encoding.
+    #[inline]
+    pub const fn alphabet(self) -> &'static [u8] {
+        match self {
+            SecretEncoding::Hex => &HEX,
+            SecretEncoding::Base32 => &BASE32,
+            SecretEncoding::Base64Url => &BASE64_URL,
+            SecretEncoding::Base58 => &BASE58,
+        }
+    }
+
+    /// The count of bits of a character if the size of the alphabet is a power of two.
+    #[inline]
+    const fn bits_per_char(self) -> Option<usize> {
+        match self {
+            SecretEncoding::Hex => Some(4),
+            SecretEncoding::Base32 => Some(5),
+            SecretEncoding::Base64Url => Some(6),
+            SecretEncoding::Base58 => None,
+        }
+    }
+}
+
+/// This struct can help you generate encoded random secrets, such as session secrets, CSRF keys and webhook secrets.
+///
+/// Exactly `bits` uniformly random bits are encoded into every secret, so each secret carries exactly the requested entropy. If offensive words are excluded, a few more bits are encoded, so that the secrets without offensive words still carry at least `bits` bits. For hex, base32 and base64url, the bits are encoded as a bit string whose last character is padded with zero bits; if `bits` is a multiple of 8, the result is the same as encoding `bits / 8` random bytes. For base58, the bits are read as a big-endian integer and encoded with a fixed length, padded with leading `1`s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecretGenerator {
+    /// The entropy of the generated secrets, in bits.
+    ///
+    /// Default: `256`
+    pub bits:                    usize,
+    /// The encoding of the generated secrets.
+    ///
+    /// Default: `SecretEncoding::Base64Url`
+    pub encoding:                SecretEncoding,
+    /// Whether to exclude secrets containing offensive words. The secrets are lengthened if needed, so that they still carry at least `bits` bits, which is reported by the `entropy` method.
+    ///
+    /// Default: `false`
+    pub exclude_offensive_words: bool,
+}
+
+impl SecretGenerator {
+    /// Create a `SecretGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// SecretGenerator {
+    ///     bits: 256,
+    ///     encoding: SecretEncoding::Base64Url,
+    ///     exclude_offensive_words: false,
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> SecretGenerator {
+        SecretGenerator {
+            bits:                    256,
+            encoding:                SecretEncoding::Base64Url,
+            exclude_offensive_words: false,
+        }
+    }
+
+    /// The entropy of the generated secrets, in bits.
+    #[inline]
+    pub const fn bits(mut self, bits: usize) -> SecretGenerator {
+        self.bits = bits;
+
+        self
+    }
+
+    /// The encoding of the generated secrets.
+    #[inline]
+    pub const fn encoding(mut self, encoding: SecretEncoding) -> SecretGenerator {
+        self.encoding = encoding;
+
+        self
+    }
+
+    /// Whether to exclude secrets containing offensive words.
+    #[inline]
+    pub const fn exclude_offensive_words(
+        mut self,
+        exclude_offensive_words: bool,
+    ) -> SecretGenerator {
+        self.exclude_offensive_words = exclude_offensive_words;
+
+        self
+    }
+}
+
+impl SecretGenerator {
+    /// The length of secrets encoding a count of bits.
+    fn length_of(&self, bits: usize) -> usize {
+        match self.encoding.bits_per_char() {
+            Some(bits_per_char) => bits.div_ceil(bits_per_char),
+            None => (bits as f64 / (self.encoding.alphabet().len() as f64).log2()).ceil() as usize,
+        }
+    }
+
+    /// The characters allowed in the encodings of `bits` bits, as `(extras, initial_extra, choices)`.
+    fn choices_of(&self, bits: usize) -> (usize, usize, Choices) {
+        let alphabet = self.encoding.alphabet();
+        let length = self.length_of(bits);
+
+        match self.encoding.bits_per_char() {
+            Some(bits_per_char) => {
+                // the last character is padded with `padding_bits` zero bits
+                let padding_bits = length * bits_per_char - bits;
+
+                let choices = (0..length)
+                    .map(|position| {
+                        let characters = alphabet
+                            .iter()
+                            .enumerate()
+                            .filter(|&(value, _)| {
+                                position + 1 < length || value % (1 << padding_bits) == 0
+                            })
+                            .map(|(_, &b)| (b as char, 0))
+                            .collect();
+
+                        vec![characters]
+                    })
+                    .collect();
+
+                (1, 0, choices)
+            },
+            None => {
+                // the digits of the largest secret, `2^bits - 1`
+                let mut bytes = vec![0xFFu8; bits.div_ceil(8)];
+
+                bytes[0] >>= bytes.len() * 8 - bits;
+
+                let base = alphabet.len() as u32;
+
+                let mut max_digits = Vec::with_capacity(length);
+
+                for _ in 0..length {
+                    let mut remainder = 0u32;
+
+                    for b in bytes.iter_mut() {
+                        let value = (remainder << 8) | *b as u32;
+
+                        *b = (value / base) as u8;
+                        remainder = value % base;
+                    }
+
+                    max_digits.push(remainder as usize);
+                }
+
+                max_digits.reverse();
+
+                // the extra state is 1 while the prefix equals the prefix of the largest secret
+                let choices = max_digits
+                    .iter()
+                    .map(|&max_digit| {
+                        [alphabet.len() - 1, max_digit]
+                            .into_iter()
+                            .enumerate()
+                            .map(|(tight, max_digit)| {
+                                alphabet[..=max_digit]
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(digit, &b)| {
+                                        (b as char, usize::from(tight == 1 && digit == max_digit))
+                                    })
+                                    .collect()
+                            })
+                            .collect()
+                    })
+                    .collect();
+
+                (2, 1, choices)
+            },
+        }
+    }
+
+    /// The base-2 logarithm of the count of the encodings of `bits` bits which do not contain offensive words.
+    fn log2_count_clean_of(&self, bits: usize) -> f64 {
+        let (extras, initial_extra, choices) = self.choices_of(bits);
+
+        log2_count_clean(
+            self.length_of(bits),
+            extras,
+            initial_extra,
+            |position, extra| choices[position][extra].clone(),
+            |_| true,
+        )
+    }
+
+    /// The count of random bits encoded into every secret. It is `bits`, unless offensive words are excluded, in which case it is the smallest count of bits whose encodings without offensive words carry at least `bits` bits.
+    pub fn encoded_bits(&self) -> usize {
+        let mut bits = self.bits;
+
+        if self.exclude_offensive_words && bits > 0 {
+            while self.log2_count_clean_of(bits) < self.bits as f64 {
+                bits += 1;
+            }
+        }
+
+        bits
+    }
+
+    /// The length of the generated secrets.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.length_of(self.encoded_bits())
+    }
+
+    /// The entropy of the generated secrets, in bits. It is `bits`, unless offensive words are excluded, in which case the excluded secrets are not counted, so the entropy is taken into account exactly and is at least `bits`.
+    pub fn entropy(&self) -> f64 {
+        if !self.exclude_offensive_words {
+            return self.bits as f64;
+        }
+
+        self.log2_count_clean_of(self.encoded_bits())
+    }
+
+    fn fill_secret<R: Rng + ?Sized>(&self, rng: &mut R, bytes: &mut [u8], secret: &mut String) {
+        let alphabet = self.encoding.alphabet();
+        let length = self.length();
+        let unused_bits = bytes.len() * 8 - self.bits;
+
+        rng.fill_bytes(bytes);
+
+        secret.clear();
+
+        match self.encoding.bits_per_char() {
+            Some(bits_per_char) => {
+                // keep the leading `bits` bits of the bit string
+                if let Some(last) = bytes.last_mut() {
+                    *last &= 0xFFu8 << unused_bits;
+                }
+
+                let mask = (1u16 << bits_per_char) - 1;
+
+                let mut buffer = 0u16;
+                let mut buffer_bits = 0;
+                let mut bytes_iter = bytes.iter();
+
+                for _ in 0..length {
+                    if buffer_bits < bits_per_char {
+                        let b = bytes_iter.next().copied().unwrap_or(0);
+
+                        buffer = (buffer << 8) | b as u16;
+                        buffer_bits += 8;
+                    }
+
+                    buffer_bits -= bits_per_char;
+
+                    secret.push(alphabet[((buffer >> buffer_bits) & mask) as usize] as char);
+                }
+            },
+            None => {
+                // keep the trailing `bits` bits of the big-endian integer
+                if let Some(first) = bytes.first_mut() {
+                    *first &= 0xFFu8 >> unused_bits;
+                }
+
+                let base = alphabet.len() as u32;
+
+                let mut digits = Vec::with_capacity(length);
+
+                for _ in 0..length {
+                    let mut remainder = 0u32;
+
+                    for b in bytes.iter_mut() {
+                        let value = (remainder << 8) | *b as u32;
+
+                        *b = (value / base) as u8;
+                        remainder = value % base;
+                    }
+
+                    digits.push(alphabet[remainder as usize]);
+                }
+
+                secret.extend(digits.iter().rev().map(|&b| b as char));
+            },
+        }
+    }
+
+    /// Generate random secrets.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        if self.bits == 0 {
+            return Err("The entropy of secrets cannot be 0.");
+        }
+
+        let mut rng = rand::rng();
+        let mut result = Vec::with_capacity(count);
+
+        if self.exclude_offensive_words {
+            // each character is picked in proportion to the count of the encodings without offensive words which start with the picked characters, so no secret is rejected
+            let bits = self.encoded_bits();
+            let length = self.length_of(bits);
+            let (extras, initial_extra, choices) = self.choices_of(bits);
+
+            let sampler =
+                CleanSampler::new(true, length, extras, initial_extra, true, |position, extra| {
+                    choices[position][extra].clone()
+                });
+
+            for _ in 0..count {
+                let mut secret = String::with_capacity(length);
+
+                let Ok(()) = sampler.sample::<Infallible, _, _>(
+                    length,
+                    |_| Ok(rng.random()),
+                    |_, c| secret.push(c),
+                );
+
+                result.push(secret);
+            }
+        } else {
+            let mut bytes = vec![0u8; self.bits.div_ceil(8)];
+
+            for _ in 0..count {
+                let mut secret = String::with_capacity(self.length());
+
+                self.fill_secret(&mut rng, &mut bytes, &mut secret);
+
+                result.push(secret);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random secret.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+}
+
+impl Default for SecretGenerator {
+    #[inline]
+    fn default() -> SecretGenerator {
+        SecretGenerator::new()
+    }
+}
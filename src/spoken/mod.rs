@@ -0,0 +1,242 @@
+/// The words of the NATO phonetic alphabet, from `A` to `Z`.
+static NATO_ALPHABET: [&str; 26] = [
+    "Alfa", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India", "Juliett",
+    "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra", "Tango",
+    "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu",
+];
+
+static ENGLISH_DIGITS: [&str; 10] =
+    ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// `zwo` instead of `zwei`, so that it is not confused with `drei`
+static GERMAN_DIGITS: [&str; 10] =
+    ["null", "eins", "zwo", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+
+/// The names of the ASCII symbols and the space, as `(character, English, German)`.
+static SYMBOL_NAMES: [(char, &str, &str); 33] = [
+    (' ', "space", "Leerzeichen"),
+    ('!', "exclamation mark", "Ausrufezeichen"),
+    ('"', "double quote", "Anführungszeichen"),
+    ('#', "hash", "Raute"),
+    ('$', "dollar sign", "Dollarzeichen"),
+    ('%', "percent sign", "Prozentzeichen"),
+    ('&', "ampersand", "Und-Zeichen"),
+    ('\'', "apostrophe", "Apostroph"),
+    ('(', "left parenthesis", "runde Klammer auf"),
+    (')', "right parenthesis", "runde Klammer zu"),
+    ('*', "asterisk", "Sternchen"),
+    ('+', "plus sign", "Pluszeichen"),
+    (',', "comma", "Komma"),
+    ('-', "hyphen", "Bindestrich"),
+    ('.', "period", "Punkt"),
+    ('/', "slash", "Schrägstrich"),
+    (':', "colon", "Doppelpunkt"),
+    (';', "semicolon", "Semikolon"),
+    ('<', "less-than sign", "Kleiner-als-Zeichen"),
+    ('=', "equals sign", "Gleichheitszeichen"),
+    ('>', "greater-than sign", "Größer-als-Zeichen"),
+    ('?', "question mark", "Fragezeichen"),
+    ('@', "at sign", "At-Zeichen"),
+    ('[', "left square bracket", "eckige Klammer auf"),
+    ('\\', "backslash", "Backslash"),
+    (']', "right square bracket", "eckige Klammer zu"),
+    ('^', "caret", "Zirkumflex"),
+    ('_', "underscore", "Unterstrich"),
+    ('`', "backtick", "Gravis"),
+    ('{', "left curly brace", "geschweifte Klammer auf"),
+    ('|', "vertical bar", "senkrechter Strich"),
+    ('}', "right curly brace", "geschweifte Klammer zu"),
+    ('~', "tilde", "Tilde"),
+];
+
+/// The languages of spoken forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum SpokenLanguage {
+    /// `capital Bravo, seven, at sign`
+    #[default]
+    English,
+    /// `großes Bravo, sieben, At-Zeichen`
+    German,
+}
+
+impl SpokenLanguage {
+    #[inline]
+    const fn case_words(self) -> (&'static str, &'static str) {
+        match self {
+            SpokenLanguage::English => ("capital", "lowercase"),
+            SpokenLanguage::German => ("großes", "kleines"),
+        }
+    }
+
+    #[inline]
+    const fn digits(self) -> &'static [&'static str; 10] {
+        match self {
+            SpokenLanguage::English => &ENGLISH_DIGITS,
+            SpokenLanguage::German => &GERMAN_DIGITS,
+        }
+    }
+
+    #[inline]
+    fn symbol_name(self, c: char) -> Option<&'static str> {
+        SYMBOL_NAMES.iter().find(|(s, ..)| *s == c).map(|&(_, english, german)| match self {
+            SpokenLanguage::English => english,
+            SpokenLanguage::German => german,
+        })
+    }
+}
+
+/// The kinds of characters of spoken tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpokenTokenKind {
+    /// `A` to `Z`.
+    UppercaseLetter,
+    /// `a` to `z`.
+    LowercaseLetter,
+    /// `0` to `9`.
+    Digit,
+    /// An ASCII symbol.
+    Symbol,
+    /// The space.
+    Space,
+    /// A non-ASCII or control character, spoken by its Unicode name or code point.
+    Other,
+}
+
+/// A character of a password and its spoken form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpokenToken {
+    /// The character.
+    pub character: char,
+    /// The kind of the character.
+    pub kind:      SpokenTokenKind,
+    /// The spoken form of the character, like `capital Bravo`.
+    pub text:      String,
+}
+
+/// This struct can help you read passwords aloud, by rendering each character into an unambiguous spoken form.
+///
+/// Letters use the NATO phonetic alphabet with explicit case, like `capital Bravo` and `lowercase Alfa`, and digits, symbols and spaces have their own names. Other characters are spoken by their Unicode names, like `latin small letter e with acute`, in English regardless of the language, if the **unicode_names2** feature is enabled. Otherwise, or if a character has no name, its code point, like `U+00E9`, is spoken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpokenRenderer {
+    /// The language of spoken forms.
+    ///
+    /// Default: `SpokenLanguage::English`
+    pub language:  SpokenLanguage,
+    /// The separator between spoken tokens in the plain text.
+    ///
+    /// Default: `", "`
+    pub separator: &'static str,
+}
+
+impl SpokenRenderer {
+    /// Create a `SpokenRenderer` instance.
+    ///
+    /// ```rust,ignore
+    /// SpokenRenderer {
+    ///     language: SpokenLanguage::English,
+    ///     separator: ", ",
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> SpokenRenderer {
+        SpokenRenderer {
+            language: SpokenLanguage::English, separator: ", "
+        }
+    }
+
+    /// The language of spoken forms.
+    #[inline]
+    pub const fn language(mut self, language: SpokenLanguage) -> SpokenRenderer {
+        self.language = language;
+
+        self
+    }
+
+    /// The separator between spoken tokens in the plain text.
+    #[inline]
+    pub const fn separator(mut self, separator: &'static str) -> SpokenRenderer {
+        self.separator = separator;
+
+        self
+    }
+}
+
+#[cfg(feature = "unicode_names2")]
+#[inline]
+fn unicode_name(c: char) -> Option<String> {
+    unicode_names2::name(c).map(|name| name.to_string().to_lowercase())
+}
+
+#[cfg(not(feature = "unicode_names2"))]
+#[inline]
+fn unicode_name(_c: char) -> Option<String> {
+    None
+}
+
+impl SpokenRenderer {
+    /// Render a character into a spoken token.
+    pub fn token(&self, c: char) -> SpokenToken {
+        let (kind, text) = match c {
+            'A'..='Z' | 'a'..='z' => {
+                let (capital, lowercase) = self.language.case_words();
+                let word = NATO_ALPHABET[(c.to_ascii_uppercase() as u8 - b'A') as usize];
+
+                if c.is_ascii_uppercase() {
+                    (SpokenTokenKind::UppercaseLetter, format!("{capital} {word}"))
+                } else {
+                    (SpokenTokenKind::LowercaseLetter, format!("{lowercase} {word}"))
+                }
+            },
+            '0'..='9' => {
+                (SpokenTokenKind::Digit, self.language.digits()[(c as u8 - b'0') as usize].into())
+            },
+            _ => match self.language.symbol_name(c) {
+                Some(name) => {
+                    let kind =
+                        if c == ' ' { SpokenTokenKind::Space } else { SpokenTokenKind::Symbol };
+
+                    (kind, name.into())
+                },
+                None => (
+                    SpokenTokenKind::Other,
+                    unicode_name(c).unwrap_or_else(|| format!("U+{:04X}", c as u32)),
+                ),
+            },
+        };
+
+        SpokenToken {
+            character: c,
+            kind,
+            text,
+        }
+    }
+
+    /// Render a password into spoken tokens, one for each character.
+    #[inline]
+    pub fn tokens<S: AsRef<str>>(&self, password: S) -> Vec<SpokenToken> {
+        password.as_ref().chars().map(|c| self.token(c)).collect()
+    }
+
+    /// Render a password into plain text, the spoken tokens joined by the separator.
+    pub fn render<S: AsRef<str>>(&self, password: S) -> String {
+        let mut text = String::new();
+
+        for (i, c) in password.as_ref().chars().enumerate() {
+            if i > 0 {
+                text.push_str(self.separator);
+            }
+
+            text.push_str(&self.token(c).text);
+        }
+
+        text
+    }
+}
+
+impl Default for SpokenRenderer {
+    #[inline]
+    fn default() -> SpokenRenderer {
+        SpokenRenderer::new()
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    convert::Infallible,
+    sync::{Mutex, PoisonError},
+};
+
+use rand::RngExt;
+
+use crate::offensive::{CleanSampler, contains_offensive_word, log2_count_clean};
+
+static BASE62: [u8; 62] = *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The length of the base62-encoded CRC32 checksum. `62^6` is the smallest power of 62 which is bigger than `2^32`.
+const CHECKSUM_LENGTH: usize = 6;
+
+static CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+
+        while k < 8 {
+            c = if c & 1 == 1 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+            k += 1;
+        }
+
+        table[i] = c;
+        i += 1;
+    }
+
+    table
+};
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+
+    for &b in data {
+        crc = CRC32_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+
+    !crc
+}
+
+fn push_checksum(token: &mut String, body: &[u8]) {
+    let mut checksum = crc32(body);
+    let mut buffer = [b'0'; CHECKSUM_LENGTH];
+
+    for b in buffer.iter_mut().rev() {
+        *b = BASE62[(checksum % 62) as usize];
+        checksum /= 62;
+    }
+
+    token.extend(buffer.iter().map(|&b| b as char));
+}
+
+/// The base-2 logarithm of the count of base62 bodies of the length which do not contain offensive words. The results are cached, because they are needed by every validation.
+fn log2_count_clean_bodies(body_length: usize) -> f64 {
+    static CACHE: Mutex<Vec<f64>> = Mutex::new(Vec::new());
+
+    let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if cache.len() <= body_length {
+        cache.resize(body_length + 1, f64::NAN);
+    }
+
+    if cache[body_length].is_nan() {
+        cache[body_length] = log2_count_clean(
+            body_length,
+            1,
+            0,
+            |_, _| BASE62.iter().map(|&b| (b as char, 0)).collect(),
+            |_| true,
+        );
+    }
+
+    cache[body_length]
+}
+
+/// This struct can help you generate prefixed API tokens which consist of `prefix_`, a random base62 body and a base62-encoded CRC32 checksum of the body, like `ghp_` tokens. Tokens in this form are easy for secret-scanning tools to detect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenGenerator {
+    /// The prefix of the generated tokens. It can only contain ASCII letters and numbers. An underscore will be appended automatically.
+    ///
+    /// Default: `"tok"`
+    pub prefix:                  String,
+    /// The minimum entropy of the random body, in bits.
+    ///
+    /// Default: `160`
+    pub entropy_bits:            usize,
+    /// Whether to exclude tokens whose body contains offensive words. The body is lengthened if needed, so that it still carries at least `entropy_bits` bits.
+    ///
+    /// Default: `false`
+    pub exclude_offensive_words: bool,
+}
+
+impl TokenGenerator {
+    /// Create a `TokenGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// TokenGenerator {
+    ///     prefix: String::from("tok"),
+    ///     entropy_bits: 160,
+    ///     exclude_offensive_words: false,
+    /// }
+    /// ```
+    #[inline]
+    pub fn new() -> TokenGenerator {
+        TokenGenerator {
+            prefix:                  String::from("tok"),
+            entropy_bits:            160,
+            exclude_offensive_words: false,
+        }
+    }
+
+    /// The prefix of the generated tokens.
+    #[inline]
+    pub fn prefix<S: Into<String>>(mut self, prefix: S) -> TokenGenerator {
+        self.prefix = prefix.into();
+
+        self
+    }
+
+    /// The minimum entropy of the random body, in bits.
+    #[inline]
+    pub const fn entropy_bits(mut self, entropy_bits: usize) -> TokenGenerator {
+        self.entropy_bits = entropy_bits;
+
+        self
+    }
+
+    /// Whether to exclude tokens whose body contains offensive words.
+    #[inline]
+    pub const fn exclude_offensive_words(
+        mut self,
+        exclude_offensive_words: bool,
+    ) -> TokenGenerator {
+        self.exclude_offensive_words = exclude_offensive_words;
+
+        self
+    }
+}
+
+impl TokenGenerator {
+    /// The length of the random body, which is the smallest count of base62 characters carrying at least `entropy_bits` bits. If offensive words are excluded, the excluded bodies are not counted.
+    pub fn body_length(&self) -> usize {
+        let mut body_length = (self.entropy_bits as f64 / 62f64.log2()).ceil() as usize;
+
+        if self.exclude_offensive_words {
+            while log2_count_clean_bodies(body_length) < self.entropy_bits as f64 {
+                body_length += 1;
+            }
+        }
+
+        body_length
+    }
+
+    /// The entropy of the random body, in bits. If offensive words are excluded, the excluded bodies are not counted, so the entropy loss is taken into account exactly. The checksum is determined by the body, so it adds no entropy and is not filtered.
+    pub fn entropy(&self) -> f64 {
+        let body_length = self.body_length();
+
+        if self.exclude_offensive_words {
+            log2_count_clean_bodies(body_length)
+        } else {
+            body_length as f64 * 62f64.log2()
+        }
+    }
+
+    /// The total length of the generated tokens, including the prefix, the underscore and the checksum.
+    #[inline]
+    pub fn token_length(&self) -> usize {
+        self.prefix.len() + 1 + self.body_length() + CHECKSUM_LENGTH
+    }
+
+    fn check(&self) -> Result<(), &'static str> {
+        if self.entropy_bits == 0 {
+            return Err("The entropy of tokens cannot be 0.");
+        }
+
+        if self.prefix.is_empty() {
+            return Err("The prefix of tokens cannot be empty.");
+        }
+
+        if !self.prefix.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err("The prefix of tokens can only contain ASCII letters and numbers.");
+        }
+
+        Ok(())
+    }
+
+    /// Generate random tokens.
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        self.check()?;
+
+        let mut rng = rand::rng();
+        let body_length = self.body_length();
+        let token_length = self.token_length();
+
+        // each character is picked in proportion to the count of the bodies without offensive words which start with the picked characters, so no body is rejected
+        let sampler = self.exclude_offensive_words.then(|| {
+            CleanSampler::new(true, body_length, 1, 0, false, |_, _| {
+                BASE62.iter().map(|&b| (b as char, 0)).collect()
+            })
+        });
+
+        let mut body = Vec::with_capacity(body_length);
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            body.clear();
+
+            match &sampler {
+                Some(sampler) => {
+                    // all characters of the body are ASCII
+                    let Ok(()) = sampler.sample::<Infallible, _, _>(
+                        body_length,
+                        |_| Ok(rng.random()),
+                        |_, c| body.push(c as u8),
+                    );
+                },
+                None => {
+                    for _ in 0..body_length {
+                        body.push(BASE62[rng.random_range(..BASE62.len())]);
+                    }
+                },
+            }
+
+            let mut token = String::with_capacity(token_length);
+
+            token.push_str(&self.prefix);
+            token.push('_');
+            token.extend(body.iter().map(|&b| b as char));
+            push_checksum(&mut token, &body);
+
+            result.push(token);
+        }
+
+        Ok(result)
+    }
+
+    /// Generate a random token.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+
+    /// Check the prefix, the length and the checksum of a token offline, and if offensive words are excluded, the body as well. This method does not tell whether the token has been issued.
+    pub fn validate_token<S: AsRef<str>>(&self, token: S) -> bool {
+        let token = token.as_ref();
+
+        if self.check().is_err() || token.len() != self.token_length() {
+            return false;
+        }
+
+        let rest = match token.strip_prefix(self.prefix.as_str()) {
+            Some(rest) => rest,
+            None => return false,
+        };
+
+        let rest = match rest.strip_prefix('_') {
+            Some(rest) => rest.as_bytes(),
+            None => return false,
+        };
+
+        if !rest.iter().all(u8::is_ascii_alphanumeric) {
+            return false;
+        }
+
+        let (body, checksum) = rest.split_at(rest.len() - CHECKSUM_LENGTH);
+
+        if self.exclude_offensive_words
+            && contains_offensive_word(std::str::from_utf8(body).unwrap())
+        {
+            return false;
+        }
+
+        let mut expected = String::with_capacity(CHECKSUM_LENGTH);
+
+        push_checksum(&mut expected, body);
+
+        expected.as_bytes() == checksum
+    }
+}
+
+impl Default for TokenGenerator {
+    #[inline]
+    fn default() -> TokenGenerator {
+        TokenGenerator::new()
+    }
+}
@@ -0,0 +1,321 @@
+#![cfg(feature = "common-password")]
+
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::OnceLock,
+};
+
+use rand::{Rng, RngExt};
+
+use crate::analyzer::common_passwords;
+
+/// The rows of a QWERTY keyboard, from the top.
+static KEYBOARD_ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// The digits which people commonly append to passwords.
+static DIGIT_SUFFIXES: [&str; 16] = [
+    "1", "2", "7", "01", "11", "12", "13", "21", "22", "23", "99", "007", "123", "321", "1234",
+    "12345",
+];
+
+/// The symbols which people commonly append to passwords.
+static SYMBOL_SUFFIXES: [char; 7] = ['!', '@', '#', '$', '*', '.', '?'];
+
+/// The leet substitutions, as `(letter, substitutes)`.
+static LEET_SUBSTITUTIONS: [(char, &[char]); 6] = [
+    ('a', &['@', '4']),
+    ('e', &['3']),
+    ('i', &['1', '!']),
+    ('o', &['0']),
+    ('s', &['$', '5']),
+    ('t', &['7']),
+];
+
+/// The mutation rules of `WeakPasswordSynthesizer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeakPasswordRule {
+    /// A common password as it is, like `sunshine`.
+    Common,
+    /// A base word with its first letter capitalized, like `Dragon`.
+    Capitalize,
+    /// A base word followed by commonly used digits, like `monkey123`.
+    AppendDigits,
+    /// A base word followed by a year, like `summer2019`, possibly capitalized.
+    AppendYear,
+    /// A base word with leet substitutions, like `p@$$w0rd`.
+    Leet,
+    /// A walk along a row or down the columns of a QWERTY keyboard, like `qwerty` or `1qaz2wsx`.
+    KeyboardWalk,
+    /// A capitalized base word followed by digits or a year and a symbol, like `Password1!`.
+    Decoration,
+}
+
+impl WeakPasswordRule {
+    /// All mutation rules.
+    pub const ALL: [WeakPasswordRule; 7] = [
+        WeakPasswordRule::Common,
+        WeakPasswordRule::Capitalize,
+        WeakPasswordRule::AppendDigits,
+        WeakPasswordRule::AppendYear,
+        WeakPasswordRule::Leet,
+        WeakPasswordRule::KeyboardWalk,
+        WeakPasswordRule::Decoration,
+    ];
+
+    /// The label of this rule, like `append-year`.
+    #[inline]
+    pub const fn label(self) -> &'static str {
+        match self {
+            WeakPasswordRule::Common => "common",
+            WeakPasswordRule::Capitalize => "capitalize",
+            WeakPasswordRule::AppendDigits => "append-digits",
+            WeakPasswordRule::AppendYear => "append-year",
+            WeakPasswordRule::Leet => "leet",
+            WeakPasswordRule::KeyboardWalk => "keyboard-walk",
+            WeakPasswordRule::Decoration => "decoration",
+        }
+    }
+}
+
+impl Display for WeakPasswordRule {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// A weak password and the rule which produced it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SynthesizedPassword {
+    /// The password.
+    pub password: String,
+    /// The rule which produced the password.
+    pub rule:     WeakPasswordRule,
+}
+
+/// The common passwords which are printable ASCII.
+fn common_words() -> &'static [&'static str] {
+    static COMMON_WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    COMMON_WORDS.get_or_init(|| {
+        common_passwords()
+            .filter(|p| {
+                !p.is_empty() && !p.starts_with("$HEX[") && p.bytes().all(|b| b.is_ascii_graphic())
+            })
+            .collect()
+    })
+}
+
+/// The common passwords which are lowercase words of 4 to 10 letters, used as the bases of mutations.
+fn base_words() -> &'static [&'static str] {
+    static BASE_WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    BASE_WORDS.get_or_init(|| {
+        common_words()
+            .iter()
+            .copied()
+            .filter(|p| (4..=10).contains(&p.len()) && p.bytes().all(|b| b.is_ascii_lowercase()))
+            .collect()
+    })
+}
+
+#[inline]
+fn pick<'a, T, R: Rng + ?Sized>(rng: &mut R, items: &'a [T]) -> &'a T {
+    &items[rng.random_range(..items.len())]
+}
+
+#[inline]
+fn pick_base_word<R: Rng + ?Sized>(rng: &mut R) -> &'static str {
+    let words = base_words();
+
+    words[rng.random_range(..words.len())]
+}
+
+#[inline]
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(c) => c.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// This struct can help you synthesize realistic weak passwords from the table of common passwords, to test password policies and the thresholds of the `scorer` module.
+///
+/// Each password is produced by one of the mutation `rules`, picked with equal probability, and is labeled with that rule. Base words are the common passwords which are lowercase words of 4 to 10 letters. The passwords are not filtered by the offensive word blocklist, because it matches substrings of many common passwords, like `password` and `butterfly`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeakPasswordSynthesizer {
+    /// The mutation rules to apply.
+    ///
+    /// Default: `&WeakPasswordRule::ALL`
+    pub rules:    &'static [WeakPasswordRule],
+    /// The earliest year appended by the `AppendYear` and `Decoration` rules.
+    ///
+    /// Default: `1960`
+    pub min_year: u16,
+    /// The latest year appended by the `AppendYear` and `Decoration` rules.
+    ///
+    /// Default: `2030`
+    pub max_year: u16,
+}
+
+impl WeakPasswordSynthesizer {
+    /// Create a `WeakPasswordSynthesizer` instance.
+    ///
+    /// ```rust,ignore
+    /// WeakPasswordSynthesizer {
+    ///     rules: &WeakPasswordRule::ALL,
+    ///     min_year: 1960,
+    ///     max_year: 2030,
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> WeakPasswordSynthesizer {
+        WeakPasswordSynthesizer {
+            rules: &WeakPasswordRule::ALL, min_year: 1960, max_year: 2030
+        }
+    }
+
+    /// The mutation rules to apply.
+    #[inline]
+    pub const fn rules(mut self, rules: &'static [WeakPasswordRule]) -> WeakPasswordSynthesizer {
+        self.rules = rules;
+
+        self
+    }
+
+    /// The earliest year appended by the `AppendYear` and `Decoration` rules.
+    #[inline]
+    pub const fn min_year(mut self, min_year: u16) -> WeakPasswordSynthesizer {
+        self.min_year = min_year;
+
+        self
+    }
+
+    /// The latest year appended by the `AppendYear` and `Decoration` rules.
+    #[inline]
+    pub const fn max_year(mut self, max_year: u16) -> WeakPasswordSynthesizer {
+        self.max_year = max_year;
+
+        self
+    }
+}
+
+impl WeakPasswordSynthesizer {
+    fn keyboard_walk<R: Rng + ?Sized>(rng: &mut R) -> String {
+        if rng.random() {
+            // along a row, like `qwerty`
+            let row = pick(rng, &KEYBOARD_ROWS);
+            let length = rng.random_range(4..=row.len());
+            let start = rng.random_range(..=row.len() - length);
+
+            row[start..start + length].to_string()
+        } else {
+            // down the columns, like `1qaz2wsx`
+            let shortest = KEYBOARD_ROWS[3].len();
+            let columns = rng.random_range(2..=3);
+            let start = rng.random_range(..=shortest - columns);
+            let rows = rng.random_range(3..=4);
+
+            (start..start + columns)
+                .flat_map(|column| {
+                    KEYBOARD_ROWS[..rows].iter().map(move |row| &row[column..=column])
+                })
+                .collect()
+        }
+    }
+
+    fn leet<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let base = loop {
+            let base = pick_base_word(rng);
+
+            if base.chars().any(|c| LEET_SUBSTITUTIONS.iter().any(|&(letter, _)| letter == c)) {
+                break base;
+            }
+        };
+
+        // every occurrence of a letter is replaced by the same substitute
+        let substitutes: Vec<(char, char)> = LEET_SUBSTITUTIONS
+            .iter()
+            .map(|&(letter, substitutes)| (letter, *pick(rng, substitutes)))
+            .collect();
+
+        base.chars()
+            .map(|c| substitutes.iter().find(|&&(letter, _)| letter == c).map_or(c, |&(_, s)| s))
+            .collect()
+    }
+
+    fn synthesize<R: Rng + ?Sized>(&self, rng: &mut R, rule: WeakPasswordRule) -> String {
+        match rule {
+            WeakPasswordRule::Common => pick(rng, common_words()).to_string(),
+            WeakPasswordRule::Capitalize => capitalize(pick_base_word(rng)),
+            WeakPasswordRule::AppendDigits => {
+                format!("{}{}", pick_base_word(rng), pick(rng, &DIGIT_SUFFIXES))
+            },
+            WeakPasswordRule::AppendYear => {
+                let base = pick_base_word(rng);
+                let year = rng.random_range(self.min_year..=self.max_year);
+
+                if rng.random() {
+                    format!("{}{year}", capitalize(base))
+                } else {
+                    format!("{base}{year}")
+                }
+            },
+            WeakPasswordRule::Leet => Self::leet(rng),
+            WeakPasswordRule::KeyboardWalk => Self::keyboard_walk(rng),
+            WeakPasswordRule::Decoration => {
+                let base = capitalize(pick_base_word(rng));
+
+                let digits = if rng.random() {
+                    pick(rng, &DIGIT_SUFFIXES).to_string()
+                } else {
+                    rng.random_range(self.min_year..=self.max_year).to_string()
+                };
+
+                format!("{base}{digits}{}", pick(rng, &SYMBOL_SUFFIXES))
+            },
+        }
+    }
+
+    /// Synthesize weak passwords.
+    pub fn generate(&self, count: usize) -> Result<Vec<SynthesizedPassword>, &'static str> {
+        if self.rules.is_empty() {
+            return Err("There is no mutation rule to apply.");
+        }
+
+        if self.min_year > self.max_year {
+            return Err("The earliest year cannot be later than the latest year.");
+        }
+
+        let mut rng = rand::rng();
+        let mut result = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let rule = *pick(&mut rng, self.rules);
+
+            let password = self.synthesize(&mut rng, rule);
+
+            result.push(SynthesizedPassword {
+                password,
+                rule,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Synthesize a weak password.
+    #[inline]
+    pub fn generate_one(&self) -> Result<SynthesizedPassword, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+}
+
+impl Default for WeakPasswordSynthesizer {
+    #[inline]
+    fn default() -> WeakPasswordSynthesizer {
+        WeakPasswordSynthesizer::new()
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::PasswordGenerator;
+
+/// The minimum length of WPA passphrases.
+const MIN_PASSPHRASE_LENGTH: usize = 8;
+/// The maximum length of WPA passphrases.
+const MAX_PASSPHRASE_LENGTH: usize = 63;
+/// The maximum length of SSIDs, in bytes.
+const MAX_SSID_LENGTH: usize = 32;
+
+/// This struct can help you generate WPA2/WPA3 passphrases, which are 8 to 63 printable ASCII characters, for guest Wi-Fi networks.
+///
+/// The characters are picked by a `PasswordGenerator`. By default, passphrases consist of 16 numbers and lowercase letters without similar characters, so that they are easy to type on TVs and other devices without keyboards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WifiPassphraseGenerator {
+    /// The generator picking the characters. Its length, or its length range, needs to be between 8 and 63.
+    ///
+    /// Default: `PasswordGenerator::new().length(16).exclude_similar_characters(true)`
+    pub generator: PasswordGenerator,
+}
+
+impl WifiPassphraseGenerator {
+    /// Create a `WifiPassphraseGenerator` instance.
+    ///
+    /// ```rust,ignore
+    /// WifiPassphraseGenerator {
+    ///     generator: PasswordGenerator::new().length(16).exclude_similar_characters(true),
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new() -> WifiPassphraseGenerator {
+        WifiPassphraseGenerator {
+            generator: PasswordGenerator::new().length(16).exclude_similar_characters(true),
+        }
+    }
+
+    /// The generator picking the characters.
+    #[inline]
+    pub fn generator(mut self, generator: PasswordGenerator) -> WifiPassphraseGenerator {
+        self.generator = generator;
+
+        self
+    }
+}
+
+impl WifiPassphraseGenerator {
+    fn check(&self) -> Result<(), &'static str> {
+        let range = MIN_PASSPHRASE_LENGTH..=MAX_PASSPHRASE_LENGTH;
+
+        if !range.contains(&self.generator.length)
+            || !self.generator.max_length.is_none_or(|max_length| range.contains(&max_length))
+        {
+            return Err("The length of WPA passphrases must be between 8 and 63.");
+        }
+
+        Ok(())
+    }
+
+    /// The entropy of the generated passphrases, in bits.
+    #[inline]
+    pub fn entropy(&self) -> Result<f64, &'static str> {
+        self.check()?;
+
+        Ok(self.generator.try_iter()?.entropy())
+    }
+
+    /// Generate random passphrases.
+    #[inline]
+    pub fn generate(&self, count: usize) -> Result<Vec<String>, &'static str> {
+        self.check()?;
+
+        self.generator.generate(count)
+    }
+
+    /// Generate a random passphrase.
+    #[inline]
+    pub fn generate_one(&self) -> Result<String, &'static str> {
+        Ok(self.generate(1)?.remove(0))
+    }
+
+    /// Generate a random passphrase for a network and wrap it in a `WifiPayload`.
+    #[inline]
+    pub fn generate_payload<S: Into<String>>(&self, ssid: S) -> Result<WifiPayload, &'static str> {
+        WifiPayload::new(ssid, self.generate_one()?)
+    }
+}
+
+impl Default for WifiPassphraseGenerator {
+    #[inline]
+    fn default() -> WifiPassphraseGenerator {
+        WifiPassphraseGenerator::new()
+    }
+}
+
+/// The credentials of a WPA2/WPA3 network, which can be formatted to the standard `WIFI:T:WPA;S:<ssid>;P:<passphrase>;;` payload for QR codes. The characters ``\ ; , : "`` in the SSID and the passphrase are escaped with backslashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WifiPayload {
+    ssid:       String,
+    passphrase: String,
+}
+
+impl WifiPayload {
+    /// Create a `WifiPayload` instance. The SSID needs to be 1 to 32 bytes long, and the passphrase needs to be 8 to 63 printable ASCII characters.
+    pub fn new<S: Into<String>, P: Into<String>>(
+        ssid: S,
+        passphrase: P,
+    ) -> Result<WifiPayload, &'static str> {
+        let ssid = ssid.into();
+        let passphrase = passphrase.into();
+
+        if ssid.is_empty() || ssid.len() > MAX_SSID_LENGTH {
+            return Err("The SSID must be 1 to 32 bytes long.");
+        }
+
+        if !(MIN_PASSPHRASE_LENGTH..=MAX_PASSPHRASE_LENGTH).contains(&passphrase.len()) {
+            return Err("The length of WPA passphrases must be between 8 and 63.");
+        }
+
+        if !passphrase.bytes().all(|b| (b' '..=b'~').contains(&b)) {
+            return Err("WPA passphrases can only contain printable ASCII characters.");
+        }
+
+        Ok(WifiPayload {
+            ssid,
+            passphrase,
+        })
+    }
+
+    /// The SSID of the network.
+    #[inline]
+    pub fn ssid(&self) -> &str {
+        &self.ssid
+    }
+
+    /// The WPA passphrase of the network.
+    #[inline]
+    pub fn passphrase(&self) -> &str {
+        &self.passphrase
+    }
+}
+
+fn write_escaped(f: &mut Formatter<'_>, s: &str) -> fmt::Result {
+    for c in s.chars() {
+        if matches!(c, '\\' | ';' | ',' | ':' | '"') {
+            f.write_char('\\')?;
+        }
+
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+impl Display for WifiPayload {
+    /// Format the `WIFI:T:WPA;S:<ssid>;P:<passphrase>;;` payload.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("WIFI:T:WPA;S:")?;
+        write_escaped(f, &self.ssid)?;
+        f.write_str(";P:")?;
+        write_escaped(f, &self.passphrase)?;
+        f.write_str(";;")
+    }
+}
+
+#[cfg(feature = "qrcodegen")]
+impl WifiPayload {
+    /// The width of the light border around QR codes, in modules.
+    const QUIET_ZONE: i32 = 4;
+
+    fn qr_code(&self) -> qrcodegen::QrCode {
+        // a payload has at most about 200 bytes, which always fits
+        qrcodegen::QrCode::encode_text(&self.to_string(), qrcodegen::QrCodeEcc::Medium).unwrap()
+    }
+
+    /// Render the payload as a QR code in text. Every dark module is drawn as `██` and every light module as two spaces, with a quiet zone of 4 modules.
+    pub fn to_qr_text(&self) -> String {
+        let qr = self.qr_code();
+        let range = -Self::QUIET_ZONE..qr.size() + Self::QUIET_ZONE;
+
+        let mut text = String::new();
+
+        for y in range.clone() {
+            for x in range.clone() {
+                text.push_str(if qr.get_module(x, y) { "██" } else { "  " });
+            }
+
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Render the payload as a QR code in SVG, with a quiet zone of 4 modules. Each module is a unit square, so the image can be scaled freely.
+    pub fn to_qr_svg(&self) -> String {
+        let qr = self.qr_code();
+        let size = qr.size() + Self::QUIET_ZONE * 2;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" viewBox=\"0 0 {size} \
+             {size}\" stroke=\"none\"><rect width=\"100%\" height=\"100%\" \
+             fill=\"#FFFFFF\"/><path d=\""
+        );
+
+        for y in 0..qr.size() {
+            for x in 0..qr.size() {
+                if qr.get_module(x, y) {
+                    write!(svg, "M{},{}h1v1h-1z", x + Self::QUIET_ZONE, y + Self::QUIET_ZONE)
+                        .unwrap();
+                }
+            }
+        }
+
+        svg.push_str("\" fill=\"#000000\"/></svg>\n");
+
+        svg
+    }
+}
@@ -0,0 +1,125 @@
+use assert_eq_float::assert_eq_float;
+use passwords::{CompositePasswordGenerator, PasswordGenerator};
+use regex::Regex;
+
+const PASSWORD_COUNT: usize = 5000;
+
+fn upper(length: usize) -> PasswordGenerator {
+    PasswordGenerator::new()
+        .length(length)
+        .numbers(false)
+        .lowercase_letters(false)
+        .uppercase_letters(true)
+}
+
+fn digits(length: usize) -> PasswordGenerator {
+    PasswordGenerator::new().length(length).lowercase_letters(false)
+}
+
+fn symbols(length: usize) -> PasswordGenerator {
+    PasswordGenerator::new().length(length).numbers(false).lowercase_letters(false).symbols(true)
+}
+
+#[test]
+fn fixed_order() {
+    let cpg =
+        CompositePasswordGenerator::new().segment(upper(4)).segment(digits(2)).segment(symbols(1));
+
+    let re = Regex::new(r##"^[A-Z]{4}[0-9]{2}[!"#$%&'()*+,-./:;<=>?@\[\\\]^_`{|}~]$"##).unwrap();
+
+    let results = cpg.generate(PASSWORD_COUNT).unwrap();
+
+    for result in results {
+        assert!(re.is_match(&result));
+    }
+
+    // 26^4 * 10^2 * 32
+    assert_eq_float!((26f64.powi(4) * 100f64 * 32f64).log2(), cpg.entropy().unwrap());
+}
+
+#[test]
+fn shuffled() {
+    let cpg = CompositePasswordGenerator::new()
+        .segment(upper(4))
+        .segment(digits(2))
+        .segment(symbols(1))
+        .shuffle(true);
+
+    let results = cpg.generate(PASSWORD_COUNT).unwrap();
+
+    let mut fixed_order_count = 0;
+
+    for result in results {
+        assert_eq!(7, result.len());
+        assert_eq!(4, result.chars().filter(char::is_ascii_uppercase).count());
+        assert_eq!(2, result.chars().filter(char::is_ascii_digit).count());
+        assert_eq!(1, result.chars().filter(char::is_ascii_punctuation).count());
+
+        if result[..4].chars().all(|c| c.is_ascii_uppercase())
+            && result[4..6].chars().all(|c| c.is_ascii_digit())
+        {
+            fixed_order_count += 1;
+        }
+    }
+
+    // there are 7! / 4! / 2! / 1! = 105 arrangements
+    assert!(fixed_order_count < PASSWORD_COUNT / 20);
+    assert_eq_float!((26f64.powi(4) * 100f64 * 32f64 * 105f64).log2(), cpg.entropy().unwrap());
+}
+
+#[test]
+fn overlapping_segments() {
+    let cpg = CompositePasswordGenerator::new().segment(digits(2)).segment(digits(2)).shuffle(true);
+
+    // "12" + "34" and "34" + "12" can be shuffled to the same password, so the passwords are not equally likely
+    assert!(cpg.entropy().is_err());
+    assert_eq_float!(4f64 * 10f64.log2(), cpg.entropy_lower_bound().unwrap());
+
+    let cpg = cpg.shuffle(false);
+
+    assert_eq_float!(cpg.entropy().unwrap(), cpg.entropy_lower_bound().unwrap());
+}
+
+#[test]
+fn strict_segment() {
+    let cpg = CompositePasswordGenerator::new()
+        .segment(PasswordGenerator::new().length(2).strict(true))
+        .segment(upper(1));
+
+    let re = Regex::new(r"^([0-9][a-z]|[a-z][0-9])[A-Z]$").unwrap();
+
+    let results = cpg.generate(PASSWORD_COUNT).unwrap();
+
+    for result in results {
+        assert!(re.is_match(&result));
+    }
+
+    // 10 * 26 * 2 strict passwords of the first segment
+    assert_eq_float!((520f64 * 26f64).log2(), cpg.entropy().unwrap());
+}
+
+#[test]
+fn invalid_segments() {
+    assert!(CompositePasswordGenerator::new().generate_one().is_err());
+    assert!(CompositePasswordGenerator::new().segment(digits(0)).generate_one().is_err());
+    assert!(
+        CompositePasswordGenerator::new()
+            .segment(PasswordGenerator::new().length_range(2..=4))
+            .generate_one()
+            .is_err()
+    );
+    assert!(
+        CompositePasswordGenerator::new()
+            .segment(upper(3).exclude_offensive_words(true))
+            .segment(upper(3).exclude_offensive_words(true))
+            .generate_one()
+            .is_err()
+    );
+    assert!(
+        CompositePasswordGenerator::new()
+            .segment(PasswordGenerator::new().length(3).transcription_safe(true))
+            .segment(PasswordGenerator::new().length(3).transcription_safe(true))
+            .generate_one()
+            .is_err()
+    );
+}
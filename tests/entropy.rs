@@ -0,0 +1,140 @@
+use passwords::{
+    ExternalEntropy, ExternalEntropyError, PassphraseGenerator, PasswordGenerator, PinGenerator,
+};
+
+const PASSWORD_COUNT: usize = 5000;
+
+#[test]
+fn input() {
+    assert!(ExternalEntropy::from_dice_rolls("1 2 3\n4 5 6").is_ok());
+    assert!(ExternalEntropy::from_dice_rolls("1 2 7").is_err());
+    assert!(ExternalEntropy::from_dice_rolls("0").is_err());
+
+    let mut entropy = ExternalEntropy::from_dice_rolls("123").unwrap();
+
+    assert_eq!(3, entropy.remaining());
+    assert!(entropy.extend_dice_rolls("456").is_ok());
+    assert!(entropy.extend_bytes([0u8]).is_err());
+    assert_eq!(6, entropy.remaining());
+
+    assert!(ExternalEntropy::from_bytes([1, 2]).extend_dice_rolls("1").is_err());
+}
+
+#[test]
+fn unbiased() {
+    let pg = PasswordGenerator::new().length(1).lowercase_letters(false);
+
+    let mut counts = [0usize; 10];
+    let mut rejected = 0;
+
+    // two rolls have 36 combinations, of which 30 are mapped onto 10 digits and 6 are rejected
+    for a in 1..=6 {
+        for b in 1..=6 {
+            let mut entropy = ExternalEntropy::from_dice_rolls(format!("{a}{b}")).unwrap();
+
+            match pg.generate_with_entropy(&mut entropy) {
+                Ok(password) => counts[password.parse::<usize>().unwrap()] += 1,
+                Err(error) => {
+                    assert_eq!(
+                        ExternalEntropyError::Exhausted {
+                            needed: 2
+                        },
+                        error
+                    );
+
+                    rejected += 1;
+                },
+            }
+        }
+    }
+
+    assert_eq!([3; 10], counts);
+    assert_eq!(6, rejected);
+}
+
+#[test]
+fn exhausted() {
+    // 36 characters need exactly 2 rolls each, without rejection
+    let pg = PasswordGenerator::new();
+
+    let mut entropy = ExternalEntropy::from_dice_rolls("3615243615").unwrap();
+
+    assert_eq!(
+        Err(ExternalEntropyError::Exhausted {
+            needed: 6
+        }),
+        pg.generate_with_entropy(&mut entropy)
+    );
+    assert_eq!(0, entropy.consumed());
+
+    entropy.extend_dice_rolls("524361").unwrap();
+
+    let password = pg.generate_with_entropy(&mut entropy).unwrap();
+
+    assert_eq!(8, password.len());
+    assert_eq!(16, entropy.consumed());
+
+    // the same input yields the same password
+    let mut entropy = ExternalEntropy::from_dice_rolls("3615243615524361").unwrap();
+
+    assert_eq!(password, pg.generate_with_entropy(&mut entropy).unwrap());
+
+    // the length is picked first
+    let pg = PasswordGenerator::new().length_range(8..=12);
+
+    assert_eq!(
+        Err(ExternalEntropyError::Exhausted {
+            needed: 17
+        }),
+        pg.generate_with_entropy(&mut ExternalEntropy::from_dice_rolls("").unwrap())
+    );
+}
+
+#[test]
+fn generators() {
+    let bytes: Vec<u8> = (0..PASSWORD_COUNT).map(|i| (i * 7919 % 256) as u8).collect();
+
+    let mut entropy = ExternalEntropy::from_bytes(&bytes);
+
+    let pg = PasswordGenerator::new().length(12).uppercase_letters(true).symbols(true).strict(true);
+
+    let pgi = pg.try_iter().unwrap();
+
+    for _ in 0..10 {
+        let password = pgi.generate_with_entropy(&mut entropy).unwrap();
+
+        assert_eq!(12, password.len());
+        assert!(password.bytes().any(|b| b.is_ascii_punctuation()));
+    }
+
+    // 2048 words need exactly 2 bytes each
+    let pg = PassphraseGenerator::new();
+
+    let mut entropy = ExternalEntropy::from_bytes(&bytes[..11]);
+
+    assert_eq!(
+        Err(ExternalEntropyError::Exhausted {
+            needed: 1
+        }),
+        pg.generate_with_entropy(&mut entropy)
+    );
+
+    entropy.extend_bytes(&bytes[11..12]).unwrap();
+
+    assert_eq!(6, pg.generate_with_entropy(&mut entropy).unwrap().split('-').count());
+
+    let pg = PinGenerator::new();
+
+    let mut entropy = ExternalEntropy::from_bytes(&bytes);
+
+    for _ in 0..10 {
+        let pin = pg.generate_with_entropy(&mut entropy).unwrap();
+
+        assert!(!pg.is_weak(&pin));
+    }
+
+    assert!(matches!(
+        PinGenerator::new().length(0).generate_with_entropy(&mut entropy),
+        Err(ExternalEntropyError::InvalidSettings(_))
+    ));
+}
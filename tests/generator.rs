@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use passwords::PasswordGenerator;
 use regex::Regex;
 
@@ -541,3 +543,519 @@ fn length_zero() {
 fn no_characters() {
     PasswordGenerator::new().numbers(false).lowercase_letters(false).try_iter().unwrap();
 }
+
+#[cfg(feature = "crypto")]
+#[test]
+fn derive() {
+    let pg = PasswordGenerator::new()
+        .length(16)
+        .uppercase_letters(true)
+        .symbols(true)
+        .exclude_similar_characters(true)
+        .strict(true);
+
+    let password = pg.derive("master secret", "example.org", "alice", 1).unwrap();
+
+    assert_eq!(password, pg.derive("master secret", "example.org", "alice", 1).unwrap());
+
+    let re =
+        Regex::new(r"^[2-9a-hj-km-np-zA-HJ-NP-Z!#$%&()*+,\-./:;<=>?@\[\\\]^_{}~]{16}$").unwrap();
+
+    assert!(re.is_match(&password));
+    assert!(password.chars().any(|c| c.is_ascii_digit()));
+    assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+    assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+    assert!(password.chars().any(|c| c.is_ascii_punctuation()));
+
+    assert_ne!(password, pg.derive("master secret", "example.org", "alice", 2).unwrap());
+    assert_ne!(password, pg.derive("master secret", "example.org", "bob", 1).unwrap());
+    assert_ne!(password, pg.derive("master secret", "example.com", "alice", 1).unwrap());
+    assert_ne!(password, pg.derive("Master secret", "example.org", "alice", 1).unwrap());
+
+    assert!(pg.derive("", "example.org", "alice", 1).is_err());
+
+    // derived passwords must stay the same across versions
+    assert_eq!(
+        "hl5zxryj",
+        PasswordGenerator::new().derive("master secret", "example.org", "alice", 1).unwrap()
+    );
+
+    // the length is derived as well
+    let pg = PasswordGenerator::new().length_range(12..=16);
+
+    let password = pg.derive("master secret", "example.org", "alice", 1).unwrap();
+
+    assert_eq!(password, pg.derive("master secret", "example.org", "alice", 1).unwrap());
+    assert!((12..=16).contains(&password.len()));
+}
+
+#[cfg(feature = "crypto")]
+#[test]
+fn generate_hashed() {
+    use passwords::hasher;
+
+    let pg = PasswordGenerator::new().length(71).uppercase_letters(true).symbols(true);
+
+    let (password, hashed) = pg.generate_hashed(4).unwrap();
+
+    assert_eq!(71, password.len());
+    assert!(hashed.starts_with("$2b$04$"));
+    assert!(unsafe { hasher::identify_bcrypt_format(&format!("{password}\0"), &hashed) });
+    assert!(unsafe { !hasher::identify_bcrypt_format(&format!("{}\0", &password[1..]), &hashed) });
+
+    assert!(pg.clone().length(72).generate_hashed(4).is_err());
+    assert!(pg.length_range(8..=72).generate_hashed(4).is_err());
+    assert!(PasswordGenerator::new().generate_hashed(32).is_err());
+}
+
+#[test]
+fn spec() {
+    let pg: PasswordGenerator =
+        "len=16,upper,lower,digits,symbols,no-similar,strict".parse().unwrap();
+
+    assert_eq!(
+        PasswordGenerator::new()
+            .length(16)
+            .uppercase_letters(true)
+            .symbols(true)
+            .exclude_similar_characters(true)
+            .strict(true),
+        pg
+    );
+    assert_eq!("len=16,digits,lower,upper,symbols,no-similar,strict", pg.to_string());
+
+    assert_eq!("len=8,digits,lower", PasswordGenerator::new().to_string());
+    assert_eq!(PasswordGenerator::new(), " digits , lower ".parse().unwrap());
+    assert_eq!(
+        PasswordGenerator::new().length(4).lowercase_letters(false),
+        "len=4,digits,len=4".parse().unwrap()
+    );
+
+    for numbers in [false, true] {
+        for spaces in [false, true] {
+            for strict in [false, true] {
+                let pg = PasswordGenerator::new()
+                    .length(20)
+                    .numbers(numbers)
+                    .spaces(spaces)
+                    .strict(strict);
+
+                assert_eq!(pg, pg.to_string().parse().unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn spec_errors() {
+    use passwords::ParsePasswordGeneratorError;
+
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::UnknownToken("digit".to_string())),
+        "len=8,digit".parse::<PasswordGenerator>()
+    );
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::DuplicatedToken("upper".to_string())),
+        "upper,lower,upper".parse::<PasswordGenerator>()
+    );
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::InvalidLength("-1".to_string())),
+        "len=-1,lower".parse::<PasswordGenerator>()
+    );
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::ConflictingLengths(8, 12)),
+        "len=8,lower,len=12".parse::<PasswordGenerator>()
+    );
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::ConflictingLengthRanges(
+            "8".to_string(),
+            "8-12".to_string()
+        )),
+        "len=8,lower,len=8-12".parse::<PasswordGenerator>()
+    );
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::DuplicatedToken("len-proportional".to_string())),
+        "len-proportional,lower,len-proportional".parse::<PasswordGenerator>()
+    );
+    assert_eq!(Err(ParsePasswordGeneratorError::EmptyToken), "lower,".parse::<PasswordGenerator>());
+
+    for value in ["abc", "a", "ab+", "%2", "%zza", "%FFa"] {
+        assert_eq!(
+            Err(ParsePasswordGeneratorError::InvalidConfusablePairs(value.to_string())),
+            format!("lower,pairs={value}").parse::<PasswordGenerator>()
+        );
+    }
+
+    assert_eq!(
+        Err(ParsePasswordGeneratorError::DuplicatedToken("pairs".to_string())),
+        "pairs=ab,lower,pairs=ab".parse::<PasswordGenerator>()
+    );
+}
+
+#[test]
+fn generate_into() {
+    let pgi = PasswordGenerator::new().strict(true).try_iter().unwrap();
+
+    let mut output = Vec::new();
+
+    pgi.generate_into(PASSWORD_COUNT, &mut output, "\n").unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+
+    let re = Regex::new(r"^[0-9a-z]{8}$").unwrap();
+
+    assert_eq!(PASSWORD_COUNT, output.split('\n').count());
+
+    for result in output.split('\n') {
+        assert!(re.is_match(result));
+    }
+
+    let mut output = Vec::new();
+
+    pgi.generate_into(0, &mut output, "\n").unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn generate_into_error() {
+    struct FullWriter;
+
+    impl std::io::Write for FullWriter {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::WriteZero.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let pgi = PasswordGenerator::new().try_iter().unwrap();
+
+    assert!(pgi.generate_into(1, FullWriter, "\n").is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_generate() {
+    let pgi = PasswordGenerator::new().strict(true).try_iter().unwrap();
+
+    let re = Regex::new(r"^[0-9a-z]{8}$").unwrap();
+
+    let results = pgi.par_generate(PASSWORD_COUNT);
+
+    assert_eq!(PASSWORD_COUNT, results.len());
+
+    for result in results.iter() {
+        assert!(re.is_match(result));
+        assert!(result.chars().any(|c| c.is_ascii_digit()));
+        assert!(result.chars().any(|c| c.is_ascii_lowercase()));
+    }
+
+    assert_ne!(results[0], results[1]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_generate_unique() {
+    // "0 ", " 0", ..., "9 ", " 9" are the only 20 possible passwords
+    let pgi = PasswordGenerator::new()
+        .length(2)
+        .lowercase_letters(false)
+        .spaces(true)
+        .strict(true)
+        .try_iter()
+        .unwrap();
+
+    assert!(pgi.par_generate_unique(21).is_err());
+    assert_eq!(20, pgi.par_generate_unique(20).unwrap().len());
+
+    let pgi = PasswordGenerator::new().length(3).lowercase_letters(false).try_iter().unwrap();
+
+    let mut results = pgi.par_generate_unique(1000).unwrap();
+
+    results.sort();
+    results.dedup();
+
+    assert_eq!(1000, results.len());
+}
+
+#[test]
+fn fill_buffers() {
+    let pgi = PasswordGenerator::new().length(10).strict(true).try_iter().unwrap();
+
+    let re = Regex::new(r"^[0-9a-z]{10}$").unwrap();
+
+    let mut password = String::with_capacity(10);
+
+    for _ in 0..PASSWORD_COUNT {
+        pgi.fill_string(&mut password);
+
+        assert!(re.is_match(&password));
+        assert_eq!(10, password.capacity());
+    }
+
+    let mut buffer = [0u8; 16];
+
+    for _ in 0..PASSWORD_COUNT {
+        let password = pgi.fill_bytes(&mut buffer).unwrap();
+
+        assert!(re.is_match(password));
+        assert!(password.chars().any(|c| c.is_ascii_digit()));
+    }
+
+    assert_eq!([0u8; 6], buffer[10..]);
+    assert!(pgi.fill_bytes(&mut [0u8; 9]).is_err());
+}
+
+#[test]
+fn generate_array() {
+    let pgi = PasswordGenerator::new().length(10).try_iter().unwrap();
+
+    let re = Regex::new(r"^[0-9a-z]{10}$").unwrap();
+
+    for _ in 0..PASSWORD_COUNT {
+        let password = pgi.generate_array::<16>().unwrap();
+
+        assert!(re.is_match(&password));
+        assert_eq!(10, password.len());
+        assert_eq!(password.as_bytes(), password.as_str().as_bytes());
+        assert_eq!(format!("{:?}", password.as_str()), format!("{password:?}"));
+    }
+
+    assert!(pgi.generate_array::<10>().is_ok());
+    assert!(pgi.generate_array::<9>().is_err());
+}
+
+#[test]
+fn self_test() {
+    for pg in [
+        PasswordGenerator::new(),
+        PasswordGenerator::new().length(16).uppercase_letters(true).symbols(true).spaces(true),
+        PasswordGenerator::new().length(4).exclude_similar_characters(true).strict(true),
+        PasswordGenerator::new().length(1).numbers(false).lowercase_letters(false).spaces(true),
+    ] {
+        let pgi = pg.try_iter().unwrap();
+
+        let report = pgi.self_test(PASSWORD_COUNT).unwrap();
+
+        assert_eq!(PASSWORD_COUNT, report.samples);
+        assert!(report.passed(), "{pg}: {report:?}");
+        assert!(report.uniformity.p_value > 0.0 && report.uniformity.p_value <= 1.0);
+    }
+
+    let report = PasswordGenerator::new().length(16).try_iter().unwrap().self_test(1000).unwrap();
+
+    assert_eq!(36, report.pool_size);
+    assert_eq!(35, report.uniformity.degrees_of_freedom);
+    assert_eq!(15 * 35, report.positional_bias.degrees_of_freedom);
+    // 1 + ceil((log2(16000) + 20) / log2(36))
+    assert_eq!(8, report.repetition_count.cutoff);
+    assert!(report.adaptive_proportion.cutoff > 512 / 36);
+
+    assert!(PasswordGenerator::new().try_iter().unwrap().self_test(0).is_err());
+}
+
+#[test]
+fn length_range() {
+    use passwords::LengthDistribution;
+
+    let pg = PasswordGenerator::new().length_range(12..=16).strict(true);
+
+    let pgi = pg.try_iter().unwrap();
+
+    let mut counts = [0usize; 17];
+
+    for password in pgi.generate(PASSWORD_COUNT) {
+        counts[password.len()] += 1;
+    }
+
+    // every length is picked about 1000 times
+    for count in &counts[12..=16] {
+        assert!((800..1200).contains(count), "{counts:?}");
+    }
+
+    assert_eq!(PASSWORD_COUNT, counts[12..=16].iter().sum());
+
+    // the min-entropy is decided by the minimum length
+    let min_entropy =
+        PasswordGenerator::new().length(12).strict(true).try_iter().unwrap().entropy();
+
+    assert!((pgi.entropy() - (5f64.log2() + min_entropy)).abs() < 1e-9);
+
+    // every password is equally likely, and 36 of 1332 passwords have 1 character
+    let pgi = PasswordGenerator::new()
+        .length_range(1..=2)
+        .length_distribution(LengthDistribution::Proportional)
+        .try_iter()
+        .unwrap();
+
+    assert!((pgi.entropy() - 1332f64.log2()).abs() < 1e-9);
+
+    let short = pgi.generate(PASSWORD_COUNT).iter().filter(|password| password.len() == 1).count();
+
+    assert!((50..300).contains(&short), "{short}");
+
+    // strict rules are checked against the minimum length
+    assert!(PasswordGenerator::new().length_range(1..=16).strict(true).try_iter().is_err());
+    assert!(
+        PasswordGenerator {
+            max_length: Some(12),
+            ..PasswordGenerator::new().length(16)
+        }
+        .try_iter()
+        .is_err()
+    );
+
+    // a fixed length replaces the range
+    assert_eq!(PasswordGenerator::new().length(10), pg.clone().length(10).strict(false));
+
+    let mut buffer = [0u8; 16];
+
+    assert!(pg.try_iter().unwrap().fill_bytes(&mut buffer[..15]).is_err());
+    assert!((12..=16).contains(&pg.try_iter().unwrap().fill_bytes(&mut buffer).unwrap().len()));
+    assert!((12..=16).contains(&pg.try_iter().unwrap().generate_array::<16>().unwrap().len()));
+
+    let pg = pg.length_distribution(LengthDistribution::Proportional);
+
+    assert_eq!("len=12-16,len-proportional,digits,lower,strict", pg.to_string());
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+}
+
+#[test]
+fn transcription_safe() {
+    let alphabet: Vec<char> = ('0'..='9').chain('a'..='z').collect();
+
+    let contains_pair = |s: &str| {
+        let chars: Vec<char> = s.chars().collect();
+
+        chars
+            .windows(2)
+            .any(|w| PasswordGenerator::DEFAULT_CONFUSABLE_PAIRS.contains(&[w[0], w[1]]))
+    };
+
+    let pg = PasswordGenerator::new().length(12).transcription_safe(true);
+
+    for password in pg.generate(PASSWORD_COUNT).unwrap() {
+        assert!(!contains_pair(&password), "{password}");
+    }
+
+    // count the passwords of 3 characters by brute force
+    for (strict, exclude_offensive_words) in [(false, false), (true, false), (true, true)] {
+        let pgi = PasswordGenerator::new()
+            .length(3)
+            .strict(strict)
+            .exclude_offensive_words(exclude_offensive_words)
+            .transcription_safe(true)
+            .try_iter()
+            .unwrap();
+
+        let mut count = 0u32;
+
+        for a in &alphabet {
+            for b in &alphabet {
+                for c in &alphabet {
+                    let s: String = [a, b, c].into_iter().collect();
+
+                    if contains_pair(&s)
+                        || (strict
+                            && !(s.bytes().any(|b| b.is_ascii_digit())
+                                && s.bytes().any(|b| b.is_ascii_lowercase())))
+                        || (exclude_offensive_words && passwords::contains_offensive_word(&s))
+                    {
+                        continue;
+                    }
+
+                    count += 1;
+                }
+            }
+        }
+
+        assert!((pgi.entropy() - (count as f64).log2()).abs() < 1e-9);
+    }
+
+    // custom pairs
+    static PAIRS: [[char; 2]; 2] = [['a', 'b'], ['b', 'a']];
+
+    let pg = PasswordGenerator::new()
+        .length(2)
+        .numbers(false)
+        .transcription_safe(true)
+        .confusable_pairs(&PAIRS);
+
+    assert!((pg.try_iter().unwrap().entropy() - (26f64 * 26.0 - 2.0).log2()).abs() < 1e-9);
+
+    // every valid password is picked
+    let mut seen = HashSet::new();
+
+    for password in pg.generate(674 * 30).unwrap() {
+        assert!(password != "ab" && password != "ba", "{password}");
+
+        seen.insert(password);
+    }
+
+    assert_eq!(674, seen.len());
+
+    assert_eq!("len=2,lower,transcription-safe,pairs=ab+ba", pg.to_string());
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+
+    // long passwords are picked character by character instead of being rejected as a whole
+    let pg = PasswordGenerator::new()
+        .length(5000)
+        .uppercase_letters(true)
+        .exclude_offensive_words(true)
+        .transcription_safe(true);
+
+    for password in pg.generate(10).unwrap() {
+        assert_eq!(5000, password.len());
+        assert!(!contains_pair(&password));
+        assert!(!passwords::contains_offensive_word(&password));
+    }
+
+    static SPACES: [[char; 2]; 1] = [[' ', ' ']];
+
+    let pg = PasswordGenerator::new()
+        .length(2)
+        .numbers(false)
+        .lowercase_letters(false)
+        .spaces(true)
+        .transcription_safe(true)
+        .confusable_pairs(&SPACES);
+
+    assert!(pg.try_iter().is_err());
+    assert_eq!("len=2,spaces,transcription-safe,pairs=%20%20", pg.to_string());
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+    assert_eq!(" ", pg.length(1).generate_one().unwrap());
+
+    // a table built at runtime
+    let pairs: Vec<[char; 2]> = ",+%é".chars().map(|c| [c, 'x']).chain([['x', 'x']]).collect();
+
+    let pg =
+        PasswordGenerator::new().symbols(true).transcription_safe(true).confusable_pairs(&pairs);
+
+    assert_eq!(
+        "len=8,digits,lower,symbols,transcription-safe,pairs=%2Cx+%2Bx+%25x+%C3%A9x+xx",
+        pg.to_string()
+    );
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+
+    for password in pg.generate(PASSWORD_COUNT).unwrap() {
+        assert!(!password.contains(",x") && !password.contains("xx"), "{password}");
+    }
+
+    let pg = PasswordGenerator::new().confusable_pairs(&[]);
+
+    assert_eq!("len=8,digits,lower,pairs=", pg.to_string());
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+
+    let pg = PasswordGenerator::new().transcription_safe(true);
+
+    assert_eq!("len=8,digits,lower,transcription-safe", pg.to_string());
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+    // the default pairs are kept as `None`
+    assert_eq!(
+        PasswordGenerator::new(),
+        PasswordGenerator::new().confusable_pairs(&PasswordGenerator::DEFAULT_CONFUSABLE_PAIRS)
+    );
+}
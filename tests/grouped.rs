@@ -0,0 +1,58 @@
+use passwords::{GroupedPasswordGenerator, PasswordGenerator};
+use regex::Regex;
+
+const GROUPED_COUNT: usize = 5000;
+
+#[test]
+fn format() {
+    let gg = GroupedPasswordGenerator::new();
+
+    let re = Regex::new(r"^[0-9a-zA-Z]{4}(-[0-9a-zA-Z]{4}){3}$").unwrap();
+
+    for result in gg.generate(GROUPED_COUNT).unwrap() {
+        assert!(re.is_match(&result));
+    }
+
+    assert_eq!("k7Qm-p2Xs-9vTn-Hb4w", gg.format("k7Qmp2Xs9vTnHb4w"));
+    assert_eq!("k7Qm p2X", gg.clone().separator(' ').format("k7Qmp2X"));
+    assert_eq!("", gg.format(""));
+}
+
+#[test]
+fn normalize_input() {
+    let gg = GroupedPasswordGenerator::new();
+
+    assert_eq!("k7Qmp2Xs9vTnHb4w", gg.normalize_input(" k7Qm-p2Xs 9vTn -\tHb4w\n"));
+
+    for (generator, group_size, separator) in [
+        (PasswordGenerator::new().length(16).uppercase_letters(true), 4, '-'),
+        (PasswordGenerator::new().length_range(10..=13).symbols(true), 3, '·'),
+        (PasswordGenerator::new().length(20).exclude_similar_characters(true), 5, ' '),
+    ] {
+        let gg = GroupedPasswordGenerator::new()
+            .generator(generator.clone())
+            .group_size(group_size)
+            .separator(separator);
+
+        for password in generator.generate(GROUPED_COUNT).unwrap() {
+            let formatted = gg.format(&password);
+
+            assert_eq!(password, gg.normalize_input(&formatted));
+            assert_eq!(password, gg.normalize_input(formatted.replace(separator, "  ")));
+        }
+    }
+}
+
+#[test]
+fn errors() {
+    let gg = GroupedPasswordGenerator::new();
+
+    assert!(gg.clone().group_size(0).generate_one().is_err());
+    assert!(gg.clone().separator('x').generate_one().is_err());
+    assert!(gg.clone().generator(PasswordGenerator::new().symbols(true)).generate_one().is_err());
+    assert!(gg.clone().generator(PasswordGenerator::new().spaces(true)).entropy().is_err());
+
+    assert!(
+        gg.generator(PasswordGenerator::new().symbols(true)).separator(' ').generate_one().is_ok()
+    );
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use passwords::MarkovPasswordGenerator;
+
+const PASSWORD_COUNT: usize = 5000;
+
+#[test]
+fn generate() {
+    let mg = MarkovPasswordGenerator::new();
+
+    for password in mg.generate(PASSWORD_COUNT).unwrap() {
+        assert!(
+            password
+                .split('-')
+                .all(|word| { !word.is_empty() && word.bytes().all(|b| b.is_ascii_lowercase()) })
+        );
+
+        let log2_probability = mg.log2_probability(&password).unwrap();
+
+        assert!(log2_probability <= -64.0, "{password}");
+    }
+
+    let mg = MarkovPasswordGenerator::new().min_entropy(40.0).separator(' ');
+
+    for password in mg.generate(PASSWORD_COUNT).unwrap() {
+        assert!(mg.log2_probability(&password).unwrap() <= -40.0, "{password}");
+        assert!(!password.contains('-'));
+    }
+}
+
+#[test]
+fn log2_probability() {
+    let mg = MarkovPasswordGenerator::new().min_entropy(0.0);
+
+    // the generation stops after the first word
+    let first = mg.log2_probability("about").unwrap();
+    let second = mg.log2_probability("above").unwrap();
+
+    assert!(first < 0.0 && second < 0.0);
+    assert_eq!(None, mg.log2_probability("about-above"));
+
+    let mg = MarkovPasswordGenerator::new().min_entropy(-first - second - 0.5);
+
+    assert!((first + second - mg.log2_probability("about-above").unwrap()).abs() < 1e-9);
+    assert_eq!(None, mg.log2_probability("about"));
+
+    for password in ["", "-", "About", "ab0ut", "about--above", "xqz"] {
+        assert_eq!(None, mg.log2_probability(password), "{password}");
+    }
+}
+
+#[test]
+fn distribution() {
+    let mg = MarkovPasswordGenerator::new().min_entropy(0.0);
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for password in mg.generate(PASSWORD_COUNT * 20).unwrap() {
+        *counts.entry(password).or_default() += 1;
+    }
+
+    let (password, &count) = counts.iter().max_by_key(|(_, count)| **count).unwrap();
+
+    let expected = mg.log2_probability(password).unwrap().exp2() * (PASSWORD_COUNT * 20) as f64;
+
+    // five standard deviations
+    assert!((count as f64 - expected).abs() < 5.0 * expected.sqrt(), "{password}");
+}
+
+#[test]
+fn errors() {
+    assert!(MarkovPasswordGenerator::new().separator('a').generate_one().is_err());
+    assert!(MarkovPasswordGenerator::new().min_entropy(-1.0).generate_one().is_err());
+    assert!(MarkovPasswordGenerator::new().min_entropy(f64::NAN).generate_one().is_err());
+}
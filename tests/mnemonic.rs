@@ -0,0 +1,235 @@
+#![cfg(feature = "mnemonic")]
+
+use passwords::{
+    MnemonicGenerator,
+    mnemonic::{self, MnemonicError},
+};
+
+const MNEMONIC_COUNT: usize = 5000;
+
+/// The English test vectors of BIP39, as `(entropy, mnemonic)`.
+static VECTORS: [(&str, &str); 24] = [
+    (
+        "00000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+         about",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ),
+    (
+        "80808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+    ),
+    ("ffffffffffffffffffffffffffffffff", "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"),
+    (
+        "000000000000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+         abandon abandon abandon abandon abandon abandon agent",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage \
+         worth useful legal will",
+    ),
+    (
+        "808080808080808080808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount \
+         doctor acoustic avoid letter always",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffff",
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+         abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+         abandon art",
+    ),
+    (
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        "legal winner thank year wave sausage worth useful legal winner thank year wave sausage \
+         worth useful legal winner thank year wave sausage worth title",
+    ),
+    (
+        "8080808080808080808080808080808080808080808080808080808080808080",
+        "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount \
+         doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo \
+         zoo vote",
+    ),
+    (
+        "9e885d952ad362caeb4efe34a8e91bd2",
+        "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+    ),
+    (
+        "6610b25967cdcca9d59875f5cb50b0ea75433311869e930b",
+        "gravity machine north sort system female filter attitude volume fold club stay feature \
+         office ecology stable narrow fog",
+    ),
+    (
+        "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+        "hamster diagram private dutch cause delay private meat slide toddler razor book happy \
+         fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+    ),
+    (
+        "c0ba5a8e914111210f2bd131f3d5e08d",
+        "scheme spot photo card baby mountain device kick cradle pact join borrow",
+    ),
+    (
+        "6d9be1ee6ebd27a258115aad99b7317b9c8d28b6d76431c3",
+        "horn tenant knee talent sponsor spell gate clip pulse soap slush warm silver nephew swap \
+         uncle crack brave",
+    ),
+    (
+        "9f6a2878b2520799a44ef18bc7df394e7061a224d2c33cd015b157d746869863",
+        "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol \
+         speed nation flash devote level hobby quick inner drive ghost inside",
+    ),
+    (
+        "23db8160a31d3e0dca3688ed941adbf3",
+        "cat swing flag economy stadium alone churn speed unique patch report train",
+    ),
+    (
+        "8197a4a47f0425faeaa69deebc05ca29c0a5b5cc76ceacc0",
+        "light rule cinnamon wrap drastic word pride squirrel upgrade then income fatal apart \
+         sustain crack supply proud access",
+    ),
+    (
+        "066dca1a2bb7e8a1db2832148ce9933eea0f3ac9548d793112d9a95c9407efad",
+        "all hour make first leader extend hole alien behind guard gospel lava path output census \
+         museum junior mass reopen famous sing advance salt reform",
+    ),
+    (
+        "f30f8c1da665478f49b001d94c5fc452",
+        "vessel ladder alter error federal sibling chat ability sun glass valve picture",
+    ),
+    (
+        "c10ec20dc3cd9f652c7fac2f1230f7a3c828389a14392f05",
+        "scissors invite lock maple supreme raw rapid void congress muscle digital elegant little \
+         brisk hair mango congress clump",
+    ),
+    (
+        "f585c11aec520db57dd353c69554b21a89b20fb0650966fa0a9d6f74fd989d8f",
+        "void come effort suffer camp survey warrior heavy shoot primary clutch crush open \
+         amazing screen patrol group space point ten exist slush involve unfold",
+    ),
+];
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn vectors() {
+    for (entropy, words) in VECTORS {
+        let entropy = from_hex(entropy);
+
+        assert_eq!(words, mnemonic::encode(&entropy).unwrap());
+        assert_eq!(entropy, mnemonic::decode(words).unwrap());
+    }
+}
+
+#[test]
+fn invalid_entropy() {
+    for length in [0, 12, 15, 17, 36] {
+        assert!(mnemonic::encode(vec![0u8; length]).is_err());
+    }
+}
+
+#[test]
+fn decode_errors() {
+    assert_eq!(Err(MnemonicError::InvalidWordCount(3)), mnemonic::decode("zoo zoo zoo"));
+
+    assert_eq!(
+        Err(MnemonicError::UnknownWord {
+            index: 11, word: "abuot".to_string()
+        }),
+        mnemonic::decode(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+             abandon abuot"
+        )
+    );
+
+    assert_eq!(
+        Err(MnemonicError::InvalidChecksum),
+        mnemonic::decode("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo")
+    );
+
+    // case-insensitive and tolerant of extra whitespace
+    assert!(mnemonic::is_valid(
+        "  Legal WINNER thank year wave sausage worth useful legal winner thank\tyellow\n"
+    ));
+}
+
+#[test]
+fn suggest_corrections() {
+    let valid = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    assert!(mnemonic::suggest_corrections(valid).is_empty());
+
+    // an unknown word
+    let corrections = mnemonic::suggest_corrections(
+        "legal winner thank year wave sausage worth usefull legal winner thank yellow",
+    );
+
+    assert_eq!(7, corrections[0].index);
+    assert_eq!("usefull", corrections[0].typed);
+    assert_eq!("useful", corrections[0].word);
+    assert_eq!(1, corrections[0].distance);
+
+    // a known word which breaks the checksum
+    let corrections = mnemonic::suggest_corrections(
+        "legal winner thank year wave sausage worth useful legal winner thank yellow"
+            .replace("sausage", "sausages"),
+    );
+
+    assert!(corrections.iter().any(|c| c.index == 5 && c.word == "sausage"));
+
+    let corrections = mnemonic::suggest_corrections(
+        "legal winner thank year wave sausage worth useful legal winner thank yellow"
+            .replace("wave", "save"),
+    );
+
+    assert!(corrections.iter().any(|c| c.index == 4 && c.word == "wave"));
+
+    for correction in corrections {
+        let mut words: Vec<&str> = valid.split(' ').collect();
+
+        words[4] = "save";
+        words[correction.index] = correction.word;
+
+        assert!(mnemonic::is_valid(words.join(" ")));
+    }
+
+    // more than one unknown word
+    assert!(
+        mnemonic::suggest_corrections(
+            "legal winner thank year wave sausagee worth usefull legal winner thank yellow"
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn generate() {
+    for words in [12, 15, 18, 21, 24] {
+        let mg = MnemonicGenerator::new().words(words);
+
+        assert_eq!(words * 32 / 3, mg.entropy());
+
+        let results = mg.generate(MNEMONIC_COUNT / 5).unwrap();
+
+        for result in results {
+            assert_eq!(words, result.split(' ').count());
+            assert_eq!(mg.entropy() / 8, mnemonic::decode(&result).unwrap().len());
+        }
+    }
+
+    assert!(MnemonicGenerator::new().words(13).generate_one().is_err());
+}
@@ -0,0 +1,204 @@
+use passwords::{
+    PasswordGenerator, SecretEncoding, SecretGenerator, TokenGenerator, contains_offensive_word,
+};
+
+const PASSWORD_COUNT: usize = 5000;
+
+#[test]
+fn contains() {
+    for s in ["fuck", "xxFuCkxx", "5h1t", "B!TCH", "p0rn", "merde", "C4ZZ0", "scheisse", "pUt4"] {
+        assert!(contains_offensive_word(s), "{s}");
+    }
+
+    for s in ["", "hello", "f-u-c-k", "shlt", "abcdefgh"] {
+        assert!(!contains_offensive_word(s), "{s}");
+    }
+}
+
+#[test]
+fn generate() {
+    let pg = PasswordGenerator::new()
+        .length(6)
+        .lowercase_letters(true)
+        .uppercase_letters(true)
+        .exclude_offensive_words(true);
+
+    for password in pg.generate(PASSWORD_COUNT).unwrap() {
+        assert!(!contains_offensive_word(&password), "{password}");
+    }
+
+    let pg = pg.strict(true);
+
+    let pgi = pg.try_iter().unwrap();
+
+    for password in pgi.generate(PASSWORD_COUNT) {
+        assert!(!contains_offensive_word(&password), "{password}");
+    }
+
+    let mut buffer = [0u8; 6];
+
+    for _ in 0..PASSWORD_COUNT {
+        assert!(!contains_offensive_word(pgi.fill_bytes(&mut buffer).unwrap()));
+    }
+
+    assert_eq!(pg, pg.to_string().parse().unwrap());
+    assert_eq!("len=6,digits,lower,upper,strict,no-offensive", pg.to_string());
+}
+
+fn brute_force_log2_count<F: Fn(&str) -> bool>(alphabet: &[char], length: usize, valid: F) -> f64 {
+    let mut count = 0u64;
+    let mut indexes = vec![0usize; length];
+    let mut s = String::with_capacity(length);
+
+    loop {
+        s.clear();
+        s.extend(indexes.iter().map(|&i| alphabet[i]));
+
+        if valid(&s) && !contains_offensive_word(&s) {
+            count += 1;
+        }
+
+        let mut position = length;
+
+        loop {
+            if position == 0 {
+                return (count as f64).log2();
+            }
+
+            position -= 1;
+            indexes[position] += 1;
+
+            if indexes[position] < alphabet.len() {
+                break;
+            }
+
+            indexes[position] = 0;
+        }
+    }
+}
+
+#[test]
+fn entropy() {
+    let alphabet: Vec<char> = ('0'..='9').chain('a'..='z').collect();
+
+    let pgi = PasswordGenerator::new().length(3).exclude_offensive_words(true).try_iter().unwrap();
+
+    let expected = brute_force_log2_count(&alphabet, 3, |_| true);
+
+    assert!((pgi.entropy() - expected).abs() < 1e-9);
+    assert!(pgi.entropy() < 3.0 * 36f64.log2());
+
+    let pgi = PasswordGenerator::new()
+        .length(3)
+        .strict(true)
+        .exclude_offensive_words(true)
+        .try_iter()
+        .unwrap();
+
+    let expected = brute_force_log2_count(&alphabet, 3, |s| {
+        s.bytes().any(|b| b.is_ascii_digit()) && s.bytes().any(|b| b.is_ascii_lowercase())
+    });
+
+    assert!((pgi.entropy() - expected).abs() < 1e-9);
+
+    let strict_entropy =
+        PasswordGenerator::new().length(3).strict(true).try_iter().unwrap().entropy();
+
+    assert!(pgi.entropy() < strict_entropy);
+
+    // long passwords do not overflow
+    let pgi =
+        PasswordGenerator::new().length(1000).exclude_offensive_words(true).try_iter().unwrap();
+
+    assert!(pgi.entropy().is_finite());
+}
+
+#[test]
+fn token() {
+    let tg = TokenGenerator::new().entropy_bits(128).exclude_offensive_words(true);
+
+    assert!(tg.entropy() >= 128.0);
+    assert!(tg.body_length() >= TokenGenerator::new().entropy_bits(128).body_length());
+
+    for token in tg.generate(PASSWORD_COUNT).unwrap() {
+        let body = &token[4..4 + tg.body_length()];
+
+        assert!(!contains_offensive_word(body), "{token}");
+        assert!(tg.validate_token(&token));
+    }
+
+    // long bodies are picked character by character instead of being rejected as a whole
+    let tg = TokenGenerator::new().entropy_bits(2048).exclude_offensive_words(true);
+    let body_length = tg.body_length();
+
+    for token in tg.generate(10).unwrap() {
+        assert!(!contains_offensive_word(&token[4..4 + body_length]), "{token}");
+    }
+}
+
+#[test]
+fn secret() {
+    for (encoding, bits, alphabet, length) in [
+        (SecretEncoding::Hex, 16, "0123456789abcdef", 4),
+        (SecretEncoding::Base32, 13, "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", 3),
+        (
+            SecretEncoding::Base58,
+            12,
+            "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            3,
+        ),
+    ] {
+        let sg = SecretGenerator::new().bits(bits).encoding(encoding).exclude_offensive_words(true);
+
+        // the secrets are lengthened to carry at least `bits` bits
+        let encoded_bits = sg.encoded_bits();
+
+        assert!(encoded_bits >= bits);
+        assert!(sg.length() >= length);
+        assert_eq!(length, sg.clone().exclude_offensive_words(false).length());
+
+        let (bits, length) = (encoded_bits, sg.length());
+
+        let alphabet: Vec<char> = alphabet.chars().collect();
+
+        // enumerate the secrets of exactly `encoded_bits` bits
+        let expected = brute_force_log2_count(&alphabet, length, |s| {
+            let mut digits =
+                s.chars().map(|c| alphabet.iter().position(|&a| a == c).unwrap() as u64);
+
+            match encoding {
+                SecretEncoding::Base58 => {
+                    digits.fold(0u64, |n, d| n * alphabet.len() as u64 + d) < 1 << bits
+                },
+                _ => {
+                    let bits_per_char = alphabet.len().trailing_zeros() as usize;
+
+                    digits.next_back().unwrap() % (1 << (length * bits_per_char - bits)) == 0
+                },
+            }
+        });
+
+        assert!((sg.entropy() - expected).abs() < 1e-9, "{encoding:?}");
+        assert!(sg.entropy() >= sg.bits as f64);
+
+        for secret in sg.generate(PASSWORD_COUNT).unwrap() {
+            assert_eq!(length, secret.len());
+            assert!(!contains_offensive_word(&secret), "{secret}");
+        }
+    }
+
+    assert_eq!(256.0, SecretGenerator::new().entropy());
+    assert_eq!(256, SecretGenerator::new().encoded_bits());
+
+    // long secrets are picked character by character instead of being rejected as a whole
+    for encoding in [SecretEncoding::Base64Url, SecretEncoding::Base58] {
+        let sg = SecretGenerator::new().bits(2048).encoding(encoding).exclude_offensive_words(true);
+
+        let length = sg.length();
+
+        for secret in sg.generate(10).unwrap() {
+            assert_eq!(length, secret.len());
+            assert!(!contains_offensive_word(&secret), "{secret}");
+        }
+    }
+}
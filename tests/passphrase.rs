@@ -0,0 +1,115 @@
+use passwords::{PassphraseGenerator, Wordlist};
+
+const PASSPHRASE_COUNT: usize = 5000;
+
+fn check_wordlist(wordlist: Wordlist, size: usize) {
+    let words = wordlist.words();
+
+    assert_eq!(size, words.len());
+    assert!(words.windows(2).all(|w| w[0] < w[1]));
+    assert!(
+        words.iter().all(|word| !word.is_empty() && word.bytes().all(|b| b.is_ascii_lowercase()))
+    );
+    assert_eq!((size as f64).log2(), wordlist.entropy_per_word());
+
+    let pg = PassphraseGenerator::new().words(4).separator(' ').wordlist(wordlist);
+
+    assert_eq!(4.0 * (size as f64).log2(), pg.entropy());
+
+    for passphrase in pg.generate(PASSPHRASE_COUNT).unwrap() {
+        let parts: Vec<&str> = passphrase.split(' ').collect();
+
+        assert_eq!(4, parts.len());
+        assert!(parts.iter().all(|part| words.binary_search(part).is_ok()));
+    }
+}
+
+#[test]
+fn generate() {
+    let passphrase = PassphraseGenerator::new().generate_one().unwrap();
+
+    assert_eq!(6, passphrase.split('-').count());
+
+    assert!(PassphraseGenerator::new().words(0).generate_one().is_err());
+}
+
+#[test]
+fn custom() {
+    static WORDS: [&str; 8] =
+        ["zulu", "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf"];
+
+    let pg = PassphraseGenerator::new().words(5).wordlist(Wordlist::Custom(&WORDS));
+
+    assert_eq!(15.0, pg.entropy());
+
+    for passphrase in pg.generate(PASSPHRASE_COUNT).unwrap() {
+        assert!(passphrase.split('-').all(|word| WORDS.contains(&word)));
+    }
+
+    assert!(PassphraseGenerator::new().wordlist(Wordlist::Custom(&[])).generate_one().is_err());
+    assert!(
+        PassphraseGenerator::new()
+            .wordlist(Wordlist::Custom(&["echo", "delta", "echo"]))
+            .generate_one()
+            .is_err()
+    );
+}
+
+#[test]
+fn english() {
+    check_wordlist(Wordlist::English, 2048);
+}
+
+#[cfg(feature = "wordlist-de")]
+#[test]
+fn german() {
+    check_wordlist(Wordlist::German, 1454);
+
+    // `ß` and umlauts are normalized
+    assert!(Wordlist::German.words().binary_search(&"sosse").is_ok());
+    assert!(Wordlist::German.words().binary_search(&"likor").is_ok());
+}
+
+#[cfg(feature = "wordlist-fr")]
+#[test]
+fn french() {
+    check_wordlist(Wordlist::French, 2048);
+
+    assert!(Wordlist::French.words().binary_search(&"eleve").is_ok());
+}
+
+#[cfg(feature = "wordlist-es")]
+#[test]
+fn spanish() {
+    check_wordlist(Wordlist::Spanish, 2048);
+
+    assert!(Wordlist::Spanish.words().binary_search(&"abaco").is_ok());
+}
+
+#[cfg(feature = "wordlist-pt")]
+#[test]
+fn portuguese() {
+    check_wordlist(Wordlist::Portuguese, 2048);
+}
+
+#[cfg(feature = "wordlist-it")]
+#[test]
+fn italian() {
+    check_wordlist(Wordlist::Italian, 2048);
+}
+
+#[cfg(feature = "wordlist-cs")]
+#[test]
+fn czech() {
+    check_wordlist(Wordlist::Czech, 2048);
+}
+
+#[cfg(feature = "wordlist-ja-romaji")]
+#[test]
+fn japanese_romaji() {
+    check_wordlist(Wordlist::JapaneseRomaji, 2048);
+
+    for word in ["aikokushin", "akachan", "arigatou", "harowin", "itchi", "shihyou"] {
+        assert!(Wordlist::JapaneseRomaji.words().binary_search(&word).is_ok(), "{word}");
+    }
+}
@@ -0,0 +1,108 @@
+use passwords::PinGenerator;
+use regex::Regex;
+
+const PIN_COUNT: usize = 5000;
+
+fn brute_force_keyspace(pg: &PinGenerator) -> u128 {
+    (0..10u64.pow(pg.length as u32))
+        .filter(|n| !pg.is_weak(format!("{:0width$}", n, width = pg.length)))
+        .count() as u128
+}
+
+#[test]
+fn random() {
+    let pg = PinGenerator::new();
+
+    let results = pg.generate(2).unwrap();
+
+    assert_eq!(2, results.len());
+    assert_ne!(results[0], results[1]);
+}
+
+#[test]
+fn length() {
+    for length in [4, 6, 8] {
+        let pg = PinGenerator::new().length(length);
+
+        let re = Regex::new(&format!("^[0-9]{{{}}}$", length)).unwrap();
+
+        let results = pg.generate(PIN_COUNT).unwrap();
+
+        for result in results {
+            assert!(re.is_match(&result));
+            assert!(!pg.is_weak(&result));
+        }
+    }
+}
+
+#[test]
+fn weak_pins() {
+    let pg = PinGenerator::new().length(4);
+
+    for pin in
+        ["1234", "0000", "1112", "9870", "1990", "2024", "1225", "3112", "2580", "123", "12a4"]
+    {
+        assert!(pg.is_weak(pin), "{pin}");
+    }
+
+    for pin in ["1358", "7039", "3705"] {
+        assert!(!pg.is_weak(pin), "{pin}");
+    }
+
+    let pg = PinGenerator::new().length(6);
+
+    for pin in ["123456", "250190", "199012", "901225", "314159"] {
+        assert!(pg.is_weak(pin), "{pin}");
+    }
+
+    let pg = PinGenerator::new().length(8);
+
+    for pin in ["19900125", "25011990", "01251990"] {
+        assert!(pg.is_weak(pin), "{pin}");
+    }
+}
+
+#[test]
+fn keyspace() {
+    assert_eq!(
+        10000,
+        PinGenerator::new()
+            .length(4)
+            .max_repeated_digits(4)
+            .max_sequence_length(4)
+            .exclude_dates(false)
+            .exclude_common(false)
+            .keyspace()
+    );
+
+    for pg in [
+        PinGenerator::new().length(4),
+        PinGenerator::new().length(4).max_repeated_digits(3).max_sequence_length(3),
+        PinGenerator::new().length(4).exclude_dates(false),
+        PinGenerator::new().length(5).exclude_common(false),
+        PinGenerator::new().length(6),
+        PinGenerator::new().length(6).max_repeated_digits(1),
+    ] {
+        assert_eq!(brute_force_keyspace(&pg), pg.keyspace());
+    }
+
+    assert!(PinGenerator::new().length(8).keyspace() < 10u128.pow(8));
+    assert!(PinGenerator::new().length(6).entropy() > PinGenerator::new().length(4).entropy());
+}
+
+#[test]
+fn invalid_options() {
+    assert!(PinGenerator::new().length(0).generate_one().is_err());
+    assert!(PinGenerator::new().max_repeated_digits(0).generate_one().is_err());
+    assert_eq!(0, PinGenerator::new().max_sequence_length(0).keyspace());
+}
+
+#[test]
+fn generate_one_repeatedly() {
+    // the keyspace of 8-digit PINs is not counted for each call
+    let pg = PinGenerator::new().length(8);
+
+    for _ in 0..100 {
+        assert!(!pg.is_weak(pg.generate_one().unwrap()));
+    }
+}
@@ -0,0 +1,65 @@
+use passwords::{PasswordGenerator, PasswordRotator};
+
+const PASSWORD_COUNT: usize = 5000;
+
+#[test]
+fn check() {
+    let pr = PasswordRotator::new();
+
+    assert!(pr.check(&["Summer2024!"], "SUMMER2025!").is_err());
+    assert!(pr.check(&["Summer2024!"], "Winter2024!").is_err());
+    assert!(pr.check(&["kitten"], "sitting").is_err());
+    assert!(pr.check(&["abcdefgh"], "hgfedcba").is_ok());
+    assert!(pr.check::<_, &str>(&[], "a").is_ok());
+
+    // an edit distance of 3
+    let pr = PasswordRotator::new().max_common_substring(usize::MAX);
+
+    assert!(!pr.is_different_enough(&["kitten"], "sitting"));
+    assert!(pr.min_edit_distance(3).is_different_enough(&["kitten"], "sitting"));
+
+    // a common substring of 4 characters, "cdef"
+    let pr = PasswordRotator::new().min_edit_distance(0);
+
+    assert!(!pr.is_different_enough(&["abcdefgh"], "xcdefx"));
+    assert!(pr.max_common_substring(4).is_different_enough(&["abcdefgh"], "xcdefx"));
+
+    // every previous password is checked
+    let pr = PasswordRotator::new();
+
+    assert!(!pr.is_different_enough(&["zzzzzzzz", "password1"], "Password2"));
+}
+
+#[test]
+fn generate() {
+    let pr = PasswordRotator::new()
+        .generator(PasswordGenerator::new().length(12).uppercase_letters(true))
+        .min_edit_distance(8)
+        .max_common_substring(2);
+
+    let mut previous = vec![pr.generator.generate_one().unwrap()];
+
+    for _ in 0..PASSWORD_COUNT / 100 {
+        let password = pr.generate(&previous).unwrap();
+
+        assert_eq!(12, password.len());
+        assert!(pr.is_different_enough(&previous, &password));
+
+        previous.push(password);
+    }
+}
+
+#[test]
+fn errors() {
+    // the distance can never exceed the length
+    let pr = PasswordRotator::new().min_edit_distance(9);
+
+    assert!(pr.generate(&["abcdefgh"]).is_err());
+
+    assert!(
+        PasswordRotator::new()
+            .generator(PasswordGenerator::new().length(0))
+            .generate(&["a"])
+            .is_err()
+    );
+}
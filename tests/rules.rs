@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use passwords::{PasswordGenerator, PasswordRules};
+
+const PASSWORD_COUNT: usize = 5000;
+
+#[test]
+fn parse() {
+    let rules: PasswordRules = "minlength: 12; maxlength: 20; required: upper; required: digit; \
+                                allowed: [-_.]; max-consecutive: 2"
+        .parse()
+        .unwrap();
+
+    assert_eq!(12, rules.min_length);
+    assert_eq!(Some(20), rules.max_length);
+    assert_eq!(Some(2), rules.max_consecutive);
+    assert_eq!(vec![('A'..='Z').collect::<Vec<char>>(), ('0'..='9').collect()], rules.required);
+    assert_eq!(vec!['-', '.', '_'], rules.allowed);
+
+    let rules: PasswordRules = " MinLength:8;minlength: 10 ; maxlength:30;maxlength:20; required: \
+                                lower, [] ];"
+        .parse()
+        .unwrap();
+
+    assert_eq!(10, rules.min_length);
+    assert_eq!(Some(20), rules.max_length);
+    assert_eq!(
+        vec![{
+            let mut set: Vec<char> = ('a'..='z').collect();
+            set.insert(0, ' ');
+            set.insert(1, ']');
+            set
+        }],
+        rules.required
+    );
+
+    assert_eq!(PasswordRules::default(), "".parse().unwrap());
+}
+
+#[test]
+fn parse_errors() {
+    for (rules, span) in [
+        ("minlength 12", 10..11),
+        ("minlength: twelve", 11..17),
+        ("minlength: 12; lengths: 3", 15..22),
+        ("required: upper; required: upperr", 27..33),
+        ("allowed: [abc", 9..13),
+        ("allowed: [ab中]", 12..15),
+        ("required: upper lower", 16..17),
+        ("required: ; minlength: 3", 10..11),
+        (": 3", 0..1),
+        ("maxlength: 0", 11..12),
+        ("max-consecutive: 0", 17..18),
+        ("maxlength: 8; minlength: 12", 25..27),
+        ("minlength: 99999999999999999999999", 11..34),
+    ] {
+        let error = rules.parse::<PasswordRules>().unwrap_err();
+
+        assert_eq!(span, error.span(), "{rules}: {error}");
+    }
+}
+
+#[test]
+fn round_trip() {
+    for rules in [
+        "required: upper; required: digit; allowed: [-_.]; max-consecutive: 2; minlength: 12; \
+         maxlength: 20",
+        "required: lower, []-]; allowed: special",
+        "allowed: ascii-printable",
+        "minlength: 8",
+    ] {
+        let parsed: PasswordRules = rules.parse().unwrap();
+
+        assert_eq!(parsed, parsed.to_string().parse().unwrap());
+    }
+
+    assert_eq!(
+        "required: upper, lower; allowed: digit, [-_]; max-consecutive: 3",
+        "required: [ABCDEFGHIJKLMNOPQRSTUVWXYZ], lower; allowed: [-0123456789_]; max-consecutive: \
+         3"
+        .parse::<PasswordRules>()
+        .unwrap()
+        .to_string()
+    );
+}
+
+#[test]
+fn generate() {
+    for rules in [
+        "minlength: 12; maxlength: 20; required: upper; required: digit; allowed: [-_.]; \
+         max-consecutive: 2",
+        "maxlength: 6; required: digit; max-consecutive: 1",
+        "minlength: 24; required: [ab]; required: [b]",
+        "",
+    ] {
+        let rules: PasswordRules = rules.parse().unwrap();
+
+        let results = rules.generate(PASSWORD_COUNT).unwrap();
+
+        for result in results {
+            assert_eq!(rules.length(), result.chars().count());
+            assert!(rules.is_satisfied_by(&result), "{result}");
+        }
+    }
+
+    // tight rules, which are met by about 1 in 10^9 blind draws
+    let rules: PasswordRules = "allowed: ascii-printable; required: [a]; required: [b]; required: \
+                                [c]; required: [d]; required: [e]; required: [f]; maxlength: 6"
+        .parse()
+        .unwrap();
+
+    for result in rules.generate(PASSWORD_COUNT).unwrap() {
+        let mut chars: Vec<char> = result.chars().collect();
+
+        chars.sort_unstable();
+
+        assert_eq!(vec!['a', 'b', 'c', 'd', 'e', 'f'], chars);
+    }
+
+    // every password is equally likely
+    for (rules, expected) in [
+        (
+            "allowed: [c]; required: [ab]; required: [b]; maxlength: 2",
+            &["ab", "ba", "bb", "bc", "cb"][..],
+        ),
+        (
+            "allowed: [c]; required: [ab]; required: [b]; maxlength: 2; max-consecutive: 1",
+            &["ab", "ba", "bc", "cb"][..],
+        ),
+    ] {
+        let rules: PasswordRules = rules.parse().unwrap();
+
+        let mut counts = HashMap::new();
+
+        for result in rules.generate(PASSWORD_COUNT).unwrap() {
+            *counts.entry(result).or_insert(0usize) += 1;
+        }
+
+        assert_eq!(expected.len(), counts.len());
+
+        let mean = PASSWORD_COUNT / expected.len();
+
+        for password in expected {
+            assert!(counts[*password].abs_diff(mean) < mean / 5, "{password}: {counts:?}");
+        }
+    }
+
+    let rules: PasswordRules = "required: [ab]; required: [bc]; maxlength: 1".parse().unwrap();
+
+    assert_eq!("b", rules.generate_one().unwrap());
+    // a single character is repeated as many times as allowed
+    let rules: PasswordRules = "allowed: [a]; max-consecutive: 2".parse().unwrap();
+
+    assert_eq!(2, rules.length());
+    assert_eq!("aa", rules.generate_one().unwrap());
+}
+
+#[test]
+fn unsatisfiable() {
+    let rules: PasswordRules = "required: [a]; max-consecutive: 2; minlength: 3".parse().unwrap();
+
+    assert!(rules.generate_one().is_err());
+
+    let rules: PasswordRules = "required: upper; required: lower; maxlength: 1".parse().unwrap();
+
+    assert!(rules.generate_one().is_err());
+}
+
+#[test]
+fn from_generator() {
+    let pg = PasswordGenerator::new().length(12).uppercase_letters(true).strict(true);
+
+    let rules = PasswordRules::try_from(&pg).unwrap();
+
+    assert_eq!(
+        "required: digit; required: lower; required: upper; minlength: 12; maxlength: 12",
+        rules.to_string()
+    );
+
+    let rules =
+        PasswordRules::try_from(PasswordGenerator::new().exclude_similar_characters(true)).unwrap();
+
+    assert_eq!(
+        "allowed: [23456789abcdefghjkmnpqrstuvwxyz]; minlength: 8; maxlength: 8",
+        rules.to_string()
+    );
+
+    assert!(PasswordRules::try_from(PasswordGenerator::new().length(0)).is_err());
+}
+
+#[test]
+fn intersection() {
+    let ldap: PasswordRules = "minlength: 10; required: upper; required: lower; required: digit; \
+                               allowed: [-_.!]"
+        .parse()
+        .unwrap();
+    let mainframe: PasswordRules =
+        "maxlength: 12; allowed: upper, lower, digit, [-_.#]; max-consecutive: 2".parse().unwrap();
+    let saas = PasswordRules::try_from(
+        PasswordGenerator::new().length(12).uppercase_letters(true).symbols(true),
+    )
+    .unwrap();
+
+    let rules =
+        PasswordRules::intersection(&[ldap.clone(), mainframe.clone(), saas.clone()]).unwrap();
+
+    assert_eq!(12, rules.min_length);
+    assert_eq!(Some(12), rules.max_length);
+    assert_eq!(Some(2), rules.max_consecutive);
+    assert_eq!(
+        "required: upper; required: lower; required: digit; allowed: upper, lower, digit, [-._]; \
+         max-consecutive: 2; minlength: 12; maxlength: 12",
+        rules.to_string()
+    );
+
+    let results = rules.generate(PASSWORD_COUNT).unwrap();
+
+    for result in results {
+        assert!(ldap.is_satisfied_by(&result));
+        assert!(mainframe.is_satisfied_by(&result));
+        assert!(saas.is_satisfied_by(&result));
+    }
+}
+
+#[test]
+fn intersection_conflicts() {
+    let short: PasswordRules = "maxlength: 8".parse().unwrap();
+    let long: PasswordRules = "minlength: 12".parse().unwrap();
+    let digits: PasswordRules = "allowed: digit".parse().unwrap();
+    let letters: PasswordRules = "allowed: upper, lower".parse().unwrap();
+    let symbol: PasswordRules = "required: [!]".parse().unwrap();
+    let any: PasswordRules = "".parse().unwrap();
+
+    let conflict =
+        PasswordRules::intersection(&[any.clone(), long.clone(), short.clone()]).unwrap_err();
+
+    assert_eq!((1, 2), (conflict.first(), conflict.second()));
+
+    let conflict =
+        PasswordRules::intersection(&[digits.clone(), any.clone(), letters.clone()]).unwrap_err();
+
+    assert_eq!((0, 2), (conflict.first(), conflict.second()));
+
+    let conflict = PasswordRules::intersection(&[any.clone(), letters, symbol]).unwrap_err();
+
+    assert_eq!((1, 2), (conflict.first(), conflict.second()));
+
+    // more required characters than the maximum length
+    let abc: PasswordRules = "required: [a]; required: [b]; required: [c]".parse().unwrap();
+    let two: PasswordRules = "maxlength: 2".parse().unwrap();
+
+    let conflict = PasswordRules::intersection(&[abc, any.clone(), two.clone()]).unwrap_err();
+
+    assert_eq!(&[0, 2], conflict.rules());
+    assert_eq!(
+        "rules #0 and rules #2 conflict: the required character sets need more characters than \
+         the maximum length",
+        conflict.to_string()
+    );
+
+    // overlapping required sets can share a character
+    let overlapping: PasswordRules = "required: [ab]; required: [bc]".parse().unwrap();
+    let one: PasswordRules = "maxlength: 1".parse().unwrap();
+
+    let rules = PasswordRules::intersection(&[overlapping, one]).unwrap();
+
+    assert!(rules.is_satisfied_by("b"));
+
+    // no pair conflicts, but the three rules do
+    let x: PasswordRules = "allowed: [x]".parse().unwrap();
+    let consecutive: PasswordRules = "max-consecutive: 2".parse().unwrap();
+    let three: PasswordRules = "minlength: 3".parse().unwrap();
+
+    let conflict =
+        PasswordRules::intersection(&[x.clone(), consecutive.clone(), three.clone()]).unwrap_err();
+
+    assert_eq!(&[0, 1, 2], conflict.rules());
+    assert_eq!(
+        "rules #0, #1, and #2 conflict: the only allowed character cannot repeat as many times as \
+         the minimum length",
+        conflict.to_string()
+    );
+
+    assert!(PasswordRules::intersection(&[x.clone(), consecutive.clone(), two]).is_ok());
+
+    let ab: PasswordRules = "allowed: [ab]".parse().unwrap();
+    let bc: PasswordRules = "allowed: [bc]".parse().unwrap();
+    let ac: PasswordRules = "allowed: [ac]".parse().unwrap();
+
+    let conflict =
+        PasswordRules::intersection(&[ab.clone(), bc.clone(), any.clone(), ac]).unwrap_err();
+
+    assert_eq!(&[0, 1, 3], conflict.rules());
+    assert_eq!("the allowed characters have nothing in common", conflict.reason());
+
+    let a_or_c: PasswordRules = "allowed: [abc]; required: [ac]".parse().unwrap();
+
+    let conflict = PasswordRules::intersection(&[ab, bc, a_or_c]).unwrap_err();
+
+    assert_eq!(&[0, 1, 2], conflict.rules());
+    assert_eq!("a required character set is not allowed by the rest", conflict.reason());
+
+    // rules which cannot be satisfied by themselves
+    let impossible: PasswordRules = "required: [a]; required: [b]; maxlength: 1".parse().unwrap();
+
+    let conflict = PasswordRules::intersection(&[any.clone(), impossible]).unwrap_err();
+
+    assert_eq!(&[1], conflict.rules());
+    assert_eq!((1, 1), (conflict.first(), conflict.second()));
+    assert!(conflict.to_string().starts_with("rules #1 cannot be satisfied: "));
+
+    // the result can always generate passwords
+    let rules = PasswordRules::intersection(&[x, "max-consecutive: 3".parse().unwrap()]).unwrap();
+
+    assert_eq!("xxx", rules.generate_one().unwrap());
+
+    let letters = |n: usize| -> PasswordRules {
+        ('a'..='z')
+            .take(n)
+            .map(|c| format!("required: [{c}]; "))
+            .collect::<String>()
+            .parse()
+            .unwrap()
+    };
+
+    assert!(PasswordRules::intersection(&[letters(16)]).unwrap().generate_one().is_ok());
+
+    let conflict = PasswordRules::intersection(&[any, letters(17)]).unwrap_err();
+
+    assert_eq!(&[1], conflict.rules());
+    assert_eq!(
+        "the distinct required character sets are more than can be generated",
+        conflict.reason()
+    );
+}
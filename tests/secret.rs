@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use passwords::{SecretEncoding, SecretGenerator};
+use regex::Regex;
+
+const SECRET_COUNT: usize = 5000;
+
+#[test]
+fn random() {
+    let sg = SecretGenerator::new();
+
+    let results = sg.generate(2).unwrap();
+
+    assert_eq!(2, results.len());
+    assert_ne!(results[0], results[1]);
+}
+
+#[test]
+fn encodings() {
+    for (encoding, bits, regex) in [
+        (SecretEncoding::Hex, 128, r"^[0-9a-f]{32}$"),
+        (SecretEncoding::Base32, 160, r"^[A-Z2-7]{32}$"),
+        (SecretEncoding::Base64Url, 256, r"^[A-Za-z0-9\-_]{43}$"),
+        (SecretEncoding::Base58, 256, r"^[1-9A-HJ-NP-Za-km-z]{44}$"),
+    ] {
+        let sg = SecretGenerator::new().bits(bits).encoding(encoding);
+
+        let re = Regex::new(regex).unwrap();
+
+        let results = sg.generate(SECRET_COUNT).unwrap();
+
+        for result in results {
+            assert_eq!(sg.length(), result.len());
+            assert!(re.is_match(&result));
+        }
+    }
+}
+
+#[test]
+fn exact_entropy() {
+    {
+        // 130 bits are 32 full hex characters and 2 more bits
+        let sg = SecretGenerator::new().bits(130).encoding(SecretEncoding::Hex);
+
+        let re = Regex::new(r"^[0-9a-f]{32}[048c]$").unwrap();
+
+        let results = sg.generate(SECRET_COUNT).unwrap();
+
+        for result in results {
+            assert!(re.is_match(&result));
+        }
+    }
+
+    {
+        // 5 bits can only be encoded into the first 32 characters of the base58 alphabet
+        let sg = SecretGenerator::new().bits(5).encoding(SecretEncoding::Base58);
+
+        let results: HashSet<String> = sg.generate(SECRET_COUNT).unwrap().into_iter().collect();
+
+        assert_eq!(32, results.len());
+
+        for result in results {
+            assert!(SecretEncoding::Base58.alphabet()[..32].contains(&result.as_bytes()[0]));
+        }
+    }
+
+    {
+        let sg = SecretGenerator::new().bits(5).encoding(SecretEncoding::Base32);
+
+        let results: HashSet<String> = sg.generate(SECRET_COUNT).unwrap().into_iter().collect();
+
+        assert_eq!(32, results.len());
+    }
+}
+
+#[test]
+fn zero_bits() {
+    assert!(SecretGenerator::new().bits(0).generate_one().is_err());
+}
@@ -0,0 +1,71 @@
+use passwords::{PasswordGenerator, SpokenLanguage, SpokenRenderer, SpokenTokenKind};
+
+const PASSWORD_COUNT: usize = 5000;
+
+#[test]
+fn render() {
+    let sr = SpokenRenderer::new();
+
+    assert_eq!(
+        "capital Bravo, seven, at sign, lowercase X-ray, space, hyphen",
+        sr.render("B7@x -")
+    );
+    assert_eq!("", sr.render(""));
+
+    let sr = sr.language(SpokenLanguage::German).separator(" / ");
+
+    assert_eq!("großes Bravo / zwo / At-Zeichen / kleines Alfa", sr.render("B2@a"));
+}
+
+#[test]
+fn tokens() {
+    let tokens = SpokenRenderer::new().tokens("Zz9~ é");
+
+    let kinds: Vec<SpokenTokenKind> = tokens.iter().map(|token| token.kind).collect();
+
+    assert_eq!(
+        vec![
+            SpokenTokenKind::UppercaseLetter,
+            SpokenTokenKind::LowercaseLetter,
+            SpokenTokenKind::Digit,
+            SpokenTokenKind::Symbol,
+            SpokenTokenKind::Space,
+            SpokenTokenKind::Other,
+        ],
+        kinds
+    );
+
+    assert_eq!('é', tokens[5].character);
+    assert_eq!("tilde", tokens[3].text);
+
+    #[cfg(feature = "unicode_names2")]
+    assert_eq!("latin small letter e with acute", tokens[5].text);
+
+    #[cfg(not(feature = "unicode_names2"))]
+    assert_eq!("U+00E9", tokens[5].text);
+
+    // control characters have no names
+    assert_eq!("U+0009", SpokenRenderer::new().token('\t').text);
+}
+
+#[test]
+fn unambiguous() {
+    let pg = PasswordGenerator::new().length(16).uppercase_letters(true).symbols(true).spaces(true);
+
+    for language in [SpokenLanguage::English, SpokenLanguage::German] {
+        let sr = SpokenRenderer::new().language(language);
+
+        let mut texts: Vec<String> = (' '..='~').map(|c| sr.token(c).text).collect();
+
+        texts.sort();
+        texts.dedup();
+
+        // every printable ASCII character has its own spoken form
+        assert_eq!(95, texts.len());
+
+        for password in pg.generate(PASSWORD_COUNT / 10).unwrap() {
+            assert_eq!(16, sr.tokens(&password).len());
+            assert!(!sr.render(&password).contains("U+"));
+        }
+    }
+}
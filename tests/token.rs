@@ -0,0 +1,51 @@
+use passwords::TokenGenerator;
+use regex::Regex;
+
+const TOKEN_COUNT: usize = 5000;
+
+#[test]
+fn format() {
+    let tg = TokenGenerator::new().prefix("ghp").entropy_bits(178);
+
+    assert_eq!(30, tg.body_length());
+    assert_eq!(40, tg.token_length());
+
+    let re = Regex::new(r"^ghp_[0-9A-Za-z]{36}$").unwrap();
+
+    let results = tg.generate(TOKEN_COUNT).unwrap();
+
+    for result in results {
+        assert!(re.is_match(&result));
+        assert!(tg.validate_token(&result));
+    }
+}
+
+#[test]
+fn random() {
+    let tg = TokenGenerator::new();
+
+    let results = tg.generate(2).unwrap();
+
+    assert_eq!(2, results.len());
+    assert_ne!(results[0], results[1]);
+}
+
+#[test]
+fn checksum() {
+    let tg = TokenGenerator::new().prefix("tok").entropy_bits(11);
+
+    // CRC32("AB") = 0x30694C07 = 812207111, which is "0sxwAh" in base62
+    assert!(tg.validate_token("tok_AB0sxwAh"));
+    assert!(!tg.validate_token("tok_BA0sxwAh"));
+    assert!(!tg.validate_token("tok_AB0sxwAH"));
+    assert!(!tg.validate_token("tk_AB0sxwAh"));
+    assert!(!tg.validate_token("tok_AB0sxwAh0"));
+    assert!(!tg.validate_token("tok-AB0sxwAh"));
+}
+
+#[test]
+fn invalid_options() {
+    assert!(TokenGenerator::new().entropy_bits(0).generate_one().is_err());
+    assert!(TokenGenerator::new().prefix("").generate_one().is_err());
+    assert!(TokenGenerator::new().prefix("my_app").generate_one().is_err());
+}
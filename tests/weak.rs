@@ -0,0 +1,72 @@
+#![cfg(feature = "common-password")]
+
+use std::collections::HashSet;
+
+use passwords::{WeakPasswordRule, WeakPasswordSynthesizer, analyzer};
+use regex::Regex;
+
+const WEAK_COUNT: usize = 5000;
+
+#[test]
+fn labels() {
+    let ws = WeakPasswordSynthesizer::new();
+
+    let results = ws.generate(WEAK_COUNT).unwrap();
+
+    let rules: HashSet<WeakPasswordRule> = results.iter().map(|r| r.rule).collect();
+
+    assert_eq!(WeakPasswordRule::ALL.len(), rules.len());
+
+    for result in results {
+        assert!(!result.password.is_empty());
+    }
+
+    assert_eq!("append-year", WeakPasswordRule::AppendYear.to_string());
+}
+
+#[test]
+fn rules() {
+    let cases: [(&[WeakPasswordRule], &str); 6] = [
+        (&[WeakPasswordRule::Capitalize], r"^[A-Z][a-z]{3,9}$"),
+        (&[WeakPasswordRule::AppendDigits], r"^[a-z]{4,10}[0-9]{1,5}$"),
+        (&[WeakPasswordRule::AppendYear], r"^[A-Za-z][a-z]{3,9}(19[6-9][0-9]|20[0-2][0-9]|2030)$"),
+        (&[WeakPasswordRule::Leet], r"^[a-z@4!1$5307]{4,10}$"),
+        (&[WeakPasswordRule::KeyboardWalk], r"^[0-9a-z]{4,12}$"),
+        (&[WeakPasswordRule::Decoration], r"^[A-Z][a-z]{3,9}[0-9]{1,5}[!@#$*.?]$"),
+    ];
+
+    for (rules, regex) in cases {
+        let ws = WeakPasswordSynthesizer::new().rules(rules);
+
+        let re = Regex::new(regex).unwrap();
+
+        for result in ws.generate(WEAK_COUNT).unwrap() {
+            assert_eq!(rules[0], result.rule);
+            assert!(re.is_match(&result.password), "{}: {}", result.rule, result.password);
+        }
+    }
+}
+
+#[test]
+fn common() {
+    let ws = WeakPasswordSynthesizer::new().rules(&[WeakPasswordRule::Common]);
+
+    // looking up the table is slow without optimizations
+    for result in ws.generate(100).unwrap() {
+        assert!(analyzer::is_common_password(&result.password));
+    }
+}
+
+#[test]
+fn password_family() {
+    // `password` is one of about 30000 base words, so it is picked about 20 times
+    let ws = WeakPasswordSynthesizer::new().rules(&[WeakPasswordRule::Capitalize]);
+
+    assert!(ws.generate(600000).unwrap().iter().any(|r| r.password == "Password"));
+}
+
+#[test]
+fn errors() {
+    assert!(WeakPasswordSynthesizer::new().rules(&[]).generate_one().is_err());
+    assert!(WeakPasswordSynthesizer::new().min_year(2030).max_year(2000).generate_one().is_err());
+}
@@ -0,0 +1,70 @@
+use passwords::{PasswordGenerator, WifiPassphraseGenerator, WifiPayload};
+
+const PASSPHRASE_COUNT: usize = 5000;
+
+#[test]
+fn generate() {
+    let wg = WifiPassphraseGenerator::new();
+
+    for passphrase in wg.generate(PASSPHRASE_COUNT).unwrap() {
+        assert_eq!(16, passphrase.len());
+        assert!(passphrase.bytes().all(|b| b.is_ascii_digit() || b.is_ascii_lowercase()));
+        assert!(!passphrase.contains(['i', 'l', 'o', '0', '1']));
+    }
+
+    let wg = WifiPassphraseGenerator::new()
+        .generator(PasswordGenerator::new().length(63).symbols(true).spaces(true));
+
+    for passphrase in wg.generate(PASSPHRASE_COUNT).unwrap() {
+        assert_eq!(63, passphrase.len());
+        assert!(WifiPayload::new("Guest", passphrase).is_ok());
+    }
+
+    for length in [7, 64] {
+        let wg = WifiPassphraseGenerator::new().generator(PasswordGenerator::new().length(length));
+
+        assert!(wg.generate_one().is_err());
+        assert!(wg.entropy().is_err());
+    }
+}
+
+#[test]
+fn payload() {
+    let payload = WifiPayload::new(r#"My "Home", Net"#, r"a\b;c:d,e").unwrap();
+
+    assert_eq!(r#"WIFI:T:WPA;S:My \"Home\"\, Net;P:a\\b\;c\:d\,e;;"#, payload.to_string());
+
+    let payload = WifiPassphraseGenerator::new().generate_payload("Guest").unwrap();
+
+    assert_eq!(format!("WIFI:T:WPA;S:Guest;P:{};;", payload.passphrase()), payload.to_string());
+}
+
+#[test]
+fn payload_errors() {
+    assert!(WifiPayload::new("", "12345678").is_err());
+    assert!(WifiPayload::new("a".repeat(33), "12345678").is_err());
+    assert!(WifiPayload::new("Guest", "1234567").is_err());
+    assert!(WifiPayload::new("Guest", "1".repeat(64)).is_err());
+    assert!(WifiPayload::new("Guest", "pass\tword").is_err());
+    assert!(WifiPayload::new("Guest", "pässword").is_err());
+}
+
+#[cfg(feature = "qrcodegen")]
+#[test]
+fn qr() {
+    let payload = WifiPayload::new("Guest", "hunter22").unwrap();
+
+    let text = payload.to_qr_text();
+    let lines: Vec<&str> = text.lines().collect();
+
+    // version 3 has 29 modules, plus the quiet zones
+    assert_eq!(37, lines.len());
+    assert!(lines.iter().all(|line| line.chars().count() == 74));
+    assert!(lines[4].starts_with("        ██████████████"));
+
+    let svg = payload.to_qr_svg();
+
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.contains("viewBox=\"0 0 37 37\""));
+    assert!(svg.contains("M4,4h1v1h-1z"));
+}